@@ -0,0 +1,296 @@
+use crate::config::Config;
+use crate::numeric::Numeric;
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+/// Builtin function, returns `None` if number type cannot represent its result.
+pub type BuiltinFn<T> = fn(&Config, Vec<T>) -> Option<T>;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Arity {
+    Exact(usize),
+    AtLeast(usize),
+}
+
+impl Arity {
+    pub fn check(&self, args_count: usize) -> bool {
+        match self {
+            Self::Exact(count) => args_count == *count,
+            Self::AtLeast(count) => args_count >= *count,
+        }
+    }
+}
+
+impl Display for Arity {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Exact(count) => write!(f, "{}", count),
+            Self::AtLeast(count) => write!(f, "at least {}", count),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Builtin<T: Numeric> {
+    name: &'static str,
+    arity: Arity,
+    func: BuiltinFn<T>,
+}
+
+impl<T: Numeric> Builtin<T> {
+    const BUILTINS: [Self; 25] = [
+        Self::new("abs", Arity::Exact(1), builtin_abs),
+        Self::new("min", Arity::AtLeast(1), builtin_min),
+        Self::new("max", Arity::AtLeast(1), builtin_max),
+        Self::new("round", Arity::Exact(1), builtin_round),
+        Self::new("floor", Arity::Exact(1), builtin_floor),
+        Self::new("ceil", Arity::Exact(1), builtin_ceil),
+        Self::new("sqrt", Arity::Exact(1), builtin_sqrt),
+        Self::new("root", Arity::Exact(2), builtin_root),
+        Self::new("exp", Arity::Exact(1), builtin_exp),
+        Self::new("ln", Arity::Exact(1), builtin_ln),
+        Self::new("log10", Arity::Exact(1), builtin_log10),
+        Self::new("log", Arity::Exact(2), builtin_log),
+        Self::new("sin", Arity::Exact(1), builtin_sin),
+        Self::new("cos", Arity::Exact(1), builtin_cos),
+        Self::new("tan", Arity::Exact(1), builtin_tan),
+        Self::new("asin", Arity::Exact(1), builtin_asin),
+        Self::new("acos", Arity::Exact(1), builtin_acos),
+        Self::new("atan", Arity::Exact(1), builtin_atan),
+        Self::new("atan2", Arity::Exact(2), builtin_atan2),
+        Self::new("sinh", Arity::Exact(1), builtin_sinh),
+        Self::new("cosh", Arity::Exact(1), builtin_cosh),
+        Self::new("tanh", Arity::Exact(1), builtin_tanh),
+        Self::new("asinh", Arity::Exact(1), builtin_asinh),
+        Self::new("acosh", Arity::Exact(1), builtin_acosh),
+        Self::new("atanh", Arity::Exact(1), builtin_atanh),
+    ];
+
+    const fn new(name: &'static str, arity: Arity, func: BuiltinFn<T>) -> Self {
+        Self { name, arity, func }
+    }
+
+    pub fn find(name: &str) -> Option<Self> {
+        Self::BUILTINS
+            .into_iter()
+            .find(|builtin| builtin.name == name)
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub fn arity(&self) -> Arity {
+        self.arity
+    }
+
+    pub fn call(&self, config: &Config, args: Vec<T>) -> Option<T> {
+        (self.func)(config, args)
+    }
+}
+
+fn first_arg<T: Numeric>(args: Vec<T>) -> T {
+    args.into_iter().next().unwrap_or_default()
+}
+
+fn two_args<T: Numeric>(args: Vec<T>) -> (T, T) {
+    let mut args = args.into_iter();
+    let first = args.next().unwrap_or_default();
+    let second = args.next().unwrap_or_default();
+
+    (first, second)
+}
+
+/// Selects the argument that is in `ord` relation to all the others.
+///
+/// Returns NaN if any argument is NaN.
+fn select_by<T: Numeric>(args: Vec<T>, ord: Ordering) -> T {
+    let mut args = args.into_iter();
+    let mut selected = args.next().unwrap_or_default();
+
+    for arg in args {
+        match arg.partial_cmp(&selected) {
+            None if arg.is_nan() => return arg,
+            None => return selected,
+            Some(arg_ord) if arg_ord == ord => selected = arg,
+            Some(_) => (),
+        }
+    }
+
+    selected
+}
+
+fn builtin_abs<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    Some(first_arg(args).abs())
+}
+
+fn builtin_min<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    Some(select_by(args, Ordering::Less))
+}
+
+fn builtin_max<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    Some(select_by(args, Ordering::Greater))
+}
+
+fn builtin_round<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    Some(first_arg(args).round())
+}
+
+fn builtin_floor<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    Some(first_arg(args).floor())
+}
+
+fn builtin_ceil<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    Some(first_arg(args).ceil())
+}
+
+fn builtin_sqrt<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).sqrt()
+}
+
+fn builtin_root<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    let (num, n) = two_args(args);
+    num.nth_root(n)
+}
+
+fn builtin_exp<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).exp()
+}
+
+fn builtin_ln<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).ln()
+}
+
+fn builtin_log10<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).log10()
+}
+
+fn builtin_log<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    let (base, num) = two_args(args);
+    num.log(base)
+}
+
+fn builtin_sin<T: Numeric>(config: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).sin(config.angle_unit())
+}
+
+fn builtin_cos<T: Numeric>(config: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).cos(config.angle_unit())
+}
+
+fn builtin_tan<T: Numeric>(config: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).tan(config.angle_unit())
+}
+
+fn builtin_asin<T: Numeric>(config: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).asin(config.angle_unit())
+}
+
+fn builtin_acos<T: Numeric>(config: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).acos(config.angle_unit())
+}
+
+fn builtin_atan<T: Numeric>(config: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).atan(config.angle_unit())
+}
+
+fn builtin_atan2<T: Numeric>(config: &Config, args: Vec<T>) -> Option<T> {
+    let (y, x) = two_args(args);
+    y.atan2(x, config.angle_unit())
+}
+
+fn builtin_sinh<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).sinh()
+}
+
+fn builtin_cosh<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).cosh()
+}
+
+fn builtin_tanh<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).tanh()
+}
+
+fn builtin_asinh<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).asinh()
+}
+
+fn builtin_acosh<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).acosh()
+}
+
+fn builtin_atanh<T: Numeric>(_: &Config, args: Vec<T>) -> Option<T> {
+    first_arg(args).atanh()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::*;
+    use crate::{evaluate, Dec64};
+
+    fn eval(expr: &str) -> Result<String, (String, usize, usize)> {
+        let ast = parse::parse(expr).unwrap();
+
+        evaluate::<Dec64>(&ast)
+            .map(|num| num.to_string())
+            .map_err(|err| {
+                let (msg, span) = err.to_tuple();
+                (msg, span.start(), span.end())
+            })
+    }
+
+    #[test]
+    fn arity() {
+        assert!(Arity::Exact(2).check(2));
+        assert!(!Arity::Exact(2).check(1));
+        assert!(!Arity::Exact(2).check(3));
+        assert!(Arity::AtLeast(1).check(1));
+        assert!(Arity::AtLeast(1).check(5));
+        assert!(!Arity::AtLeast(1).check(0));
+
+        assert_eq!(Arity::Exact(1).to_string(), "1");
+        assert_eq!(Arity::AtLeast(1).to_string(), "at least 1");
+    }
+
+    #[test]
+    fn builtins() {
+        let cases = [
+            ("abs(-2.5)", "2.5"),
+            ("min(3, 1, 2)", "1"),
+            ("max(3, 1, 2)", "3"),
+            ("max(1, 0/0, 2)", "NaN"),
+            ("round(2.5)", "3"),
+            ("floor(-2.5)", "-3"),
+            ("ceil(-2.5)", "-2"),
+            ("sqrt(16)", "4"),
+            ("root(27, 3)", "3"),
+        ];
+
+        for (expr, expected) in cases {
+            assert_eq!(eval(expr).as_deref(), Ok(expected), "{}", expr);
+        }
+    }
+
+    #[test]
+    fn unknown_builtin() {
+        assert_eq!(
+            eval("1 + foo(2)"),
+            Err((ERR__UNKNOWN_FN.to_string(), 4, 10))
+        );
+    }
+
+    #[test]
+    fn invalid_args_count() {
+        let cases = [
+            ("abs(1, 2)", "`abs` expects 1, found 2.", (0, 9)),
+            ("2 * root(8)", "`root` expects 2, found 1.", (4, 11)),
+            ("atan2(1)", "`atan2` expects 2, found 1.", (0, 8)),
+        ];
+
+        for (expr, details, (start, end)) in cases {
+            let msg = format!("{}: {}", ERR__INVALID_ARGS_COUNT, details);
+            assert_eq!(eval(expr), Err((msg, start, end)), "{}", expr);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use number::{AngleUnit, ExpRange, NumberSpec, RoundingMode};
+
+/// Settings of evaluation.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Config {
+    angle_unit: AngleUnit,
+    rounding_mode: RoundingMode,
+    exp_range: ExpRange,
+    number_spec: NumberSpec,
+}
+
+impl Config {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets unit of angles taken and returned by trigonometric functions.
+    pub fn with_angle_unit(mut self, angle_unit: AngleUnit) -> Self {
+        self.angle_unit = angle_unit;
+        self
+    }
+
+    /// Sets rounding mode of arithmetic.
+    pub fn with_rounding_mode(mut self, rounding_mode: RoundingMode) -> Self {
+        self.rounding_mode = rounding_mode;
+        self
+    }
+
+    /// Sets range of exponents of finite numbers.
+    pub fn with_exp_range(mut self, exp_range: ExpRange) -> Self {
+        self.exp_range = exp_range;
+        self
+    }
+
+    /// Sets radix and precision of numbers with runtime spec.
+    pub fn with_number_spec(mut self, number_spec: NumberSpec) -> Self {
+        self.number_spec = number_spec;
+        self
+    }
+
+    pub fn angle_unit(&self) -> AngleUnit {
+        self.angle_unit
+    }
+
+    pub fn rounding_mode(&self) -> RoundingMode {
+        self.rounding_mode
+    }
+
+    pub fn exp_range(&self) -> ExpRange {
+        self.exp_range
+    }
+
+    pub fn number_spec(&self) -> NumberSpec {
+        self.number_spec
+    }
+}
@@ -0,0 +1,62 @@
+use crate::numeric::Numeric;
+
+/// Constant value, `None` if number type cannot represent it.
+pub type ConstantFn<T> = fn() -> Option<T>;
+
+#[derive(Clone, Copy)]
+pub struct Constant<T: Numeric> {
+    name: &'static str,
+    value: ConstantFn<T>,
+}
+
+impl<T: Numeric> Constant<T> {
+    const CONSTANTS: [Self; 4] = [
+        Self::new("pi", T::pi),
+        Self::new("tau", T::tau),
+        Self::new("e", T::e),
+        Self::new("phi", T::phi),
+    ];
+
+    const fn new(name: &'static str, value: ConstantFn<T>) -> Self {
+        Self { name, value }
+    }
+
+    pub fn find(name: &str) -> Option<Self> {
+        Self::CONSTANTS
+            .into_iter()
+            .find(|constant| constant.name == name)
+    }
+
+    pub fn value(&self) -> Option<T> {
+        (self.value)()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::errors::*;
+    use crate::{evaluate, Dec64, Rational};
+
+    #[test]
+    fn constants() {
+        let ast = parse::parse("tau / pi").unwrap();
+        assert_eq!(evaluate::<Dec64>(&ast).unwrap().to_string(), "2");
+
+        let ast = parse::parse("e").unwrap();
+        assert_eq!(evaluate::<f64>(&ast).unwrap(), std::f64::consts::E);
+    }
+
+    #[test]
+    fn unknown_and_inexact_constants() {
+        let ast = parse::parse("1 + foo").unwrap();
+        let (msg, span) = evaluate::<Dec64>(&ast).unwrap_err().to_tuple();
+        assert_eq!(
+            (msg.as_str(), span.start(), span.end()),
+            (ERR__UNKNOWN_CONST, 4, 7)
+        );
+
+        let ast = parse::parse("phi").unwrap();
+        let (msg, _) = evaluate::<Rational>(&ast).unwrap_err().to_tuple();
+        assert_eq!(msg, ERR__INEXACT_CONST);
+    }
+}
@@ -0,0 +1,10 @@
+pub const ERR__INVALID_NUMBER: &str = "Invalid number";
+
+pub const ERR__UNKNOWN_FN: &str = "Unknown function.";
+pub const ERR__UNKNOWN_CONST: &str = "Unknown constant.";
+pub const ERR__INVALID_ARGS_COUNT: &str = "Invalid arguments count";
+
+pub const ERR__INEXACT_FN: &str = "Function has no exact result, evaluate it approximately.";
+pub const ERR__INEXACT_CONST: &str = "Constant has no exact value, evaluate it approximately.";
+pub const ERR__INEXACT_POW: &str =
+    "Power with fractional exponent has no exact result, evaluate it approximately.";
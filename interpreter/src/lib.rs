@@ -1,34 +1,261 @@
+mod builtins;
+mod config;
+mod constants;
+mod errors;
+mod numeric;
+
+pub use config::Config;
+pub use number::{
+    AngleUnit, ArithmeticError, Dec64, DynNumber, ExpRange, NumberSpec, Rational, RoundingMode,
+    Status,
+};
+pub use numeric::Numeric;
+
+use builtins::Builtin;
+use constants::Constant;
+use errors::*;
 use ir::ast::*;
 use ir::span::*;
-use number::Dec64;
-
-
-pub fn traverse_tree(ast: Box<SpanWrapper<Node>>) {
-
-    let mut history = vec![ast];
-
-    loop {
-        let node = match history.pop() {
-            Some(v) => v,
-            None => break,
-        };
-
-        let number = match node.borrow_val() {
-            Node::Num(n) => n,
-            Node::BinOp(bin_op_kind, lhs, rhs) => {
-                history.push(lhs.clone());
-                history.push(rhs.clone());
-                continue;
-            },
-            Node::UnOp(un_op_kind, operand) => {
-                history.push(operand.clone());
-                continue;
-            }
-            Node::Fn(_, _) => {
-                todo!()
-            },
-        };
-
-        break;
+
+pub type IResult<O = Dec64> = Result<O, SpanWrapper<String>>;
+
+fn evaluate_num<T: Numeric>(num: &str, span: &Span) -> IResult<T> {
+    T::from_literal(num).map_err(|err| {
+        SpanWrapper::new(
+            format!("{}: {}.", ERR__INVALID_NUMBER, err.to_string()),
+            span.clone(),
+        )
+    })
+}
+
+fn evaluate_const<T: Numeric>(name: &str, span: &Span) -> IResult<T> {
+    let msg = match Constant::find(name).map(|constant| constant.value()) {
+        Some(Some(val)) => return Ok(val),
+        Some(None) => ERR__INEXACT_CONST,
+        None => ERR__UNKNOWN_CONST,
+    };
+
+    Err(SpanWrapper::new(msg.to_owned(), span.clone()))
+}
+
+fn evaluate_bin_op<T: Numeric>(
+    kind: &BinOpKind,
+    lhs: &SpanWrapper<Node>,
+    rhs: &SpanWrapper<Node>,
+    config: &Config,
+) -> IResult<T> {
+    let rhs_span = rhs.borrow_to_tuple().1.clone();
+    let (lhs, rhs): (T, T) = (evaluate_node(lhs, config)?, evaluate_node(rhs, config)?);
+
+    let res = match kind {
+        BinOpKind::Add => lhs + rhs,
+        BinOpKind::Sub => lhs - rhs,
+        BinOpKind::Mul => lhs * rhs,
+        BinOpKind::Div => lhs / rhs,
+        BinOpKind::FloorDiv => lhs.div_floor(rhs),
+        BinOpKind::Mod => lhs.modulo(rhs),
+        BinOpKind::Pow => match lhs.pow(rhs) {
+            Some(res) => res,
+            None => return Err(SpanWrapper::new(ERR__INEXACT_POW.to_owned(), rhs_span)),
+        },
+    };
+
+    Ok(res)
+}
+
+fn evaluate_un_op<T: Numeric>(
+    kind: &UnOpKind,
+    operand: &SpanWrapper<Node>,
+    config: &Config,
+) -> IResult<T> {
+    let operand: T = evaluate_node(operand, config)?;
+
+    let res = match kind {
+        UnOpKind::Neg => -operand,
+    };
+
+    Ok(res)
+}
+
+fn evaluate_fn<T: Numeric>(
+    name: &str,
+    args: &[SpanWrapper<Node>],
+    span: &Span,
+    config: &Config,
+) -> IResult<T> {
+    let builtin = match Builtin::find(name) {
+        Some(builtin) => builtin,
+        None => return Err(SpanWrapper::new(ERR__UNKNOWN_FN.to_owned(), span.clone())),
+    };
+
+    if !builtin.arity().check(args.len()) {
+        return Err(SpanWrapper::new(
+            format!(
+                "{}: `{}` expects {}, found {}.",
+                ERR__INVALID_ARGS_COUNT,
+                builtin.name(),
+                builtin.arity(),
+                args.len()
+            ),
+            span.clone(),
+        ));
+    }
+
+    let args = args
+        .iter()
+        .map(|arg| evaluate_node(arg, config))
+        .collect::<IResult<Vec<_>>>()?;
+
+    builtin
+        .call(config, args)
+        .ok_or_else(|| SpanWrapper::new(ERR__INEXACT_FN.to_owned(), span.clone()))
+}
+
+fn evaluate_node<T: Numeric>(node: &SpanWrapper<Node>, config: &Config) -> IResult<T> {
+    let (val, span) = node.borrow_to_tuple();
+
+    match val {
+        Node::Num(num) => evaluate_num(num, span),
+        Node::Const(name) => evaluate_const(name, span),
+        Node::BinOp(kind, lhs, rhs) => evaluate_bin_op(kind, lhs, rhs, config),
+        Node::UnOp(kind, operand) => evaluate_un_op(kind, operand, config),
+        Node::Fn(name, args) => evaluate_fn(name, args, span, config),
+    }
+}
+
+/// Evaluates expression in given number type.
+pub fn evaluate_with<T: Numeric>(node: &SpanWrapper<Node>, config: &Config) -> IResult<T> {
+    let prev_rounding_mode = config.rounding_mode().set_current();
+    let prev_exp_range = config.exp_range().set_current();
+    let prev_number_spec = config.number_spec().set_current();
+    let res = evaluate_node(node, config);
+    prev_number_spec.set_current();
+    prev_exp_range.set_current();
+    prev_rounding_mode.set_current();
+
+    res
+}
+
+/// Evaluates expression in given number type, returns flags raised by the evaluation as well.
+///
+/// Flags of evaluation in `Rational` are clear, as it is exact.
+pub fn evaluate_with_status<T: Numeric>(
+    node: &SpanWrapper<Node>,
+    config: &Config,
+) -> (IResult<T>, Status) {
+    Status::capture(|| evaluate_with(node, config))
+}
+
+pub fn evaluate<T: Numeric>(node: &SpanWrapper<Node>) -> IResult<T> {
+    evaluate_with(node, &Config::default())
+}
+
+/// Approximates exact result in number spec of `config`, rounds with its rounding mode.
+pub fn approximate_with(num: &Rational, config: &Config) -> DynNumber {
+    let prev_rounding_mode = config.rounding_mode().set_current();
+    let prev_exp_range = config.exp_range().set_current();
+    let prev_number_spec = config.number_spec().set_current();
+    let res = DynNumber::from_rational(num);
+    prev_number_spec.set_current();
+    prev_exp_range.set_current();
+    prev_rounding_mode.set_current();
+
+    res
+}
+
+/// Evaluates expression exactly and approximates the result with `approximate_with`,
+/// so it is rounded once. Expressions that have no exact value, e.g. irrational
+/// functions and constants, are evaluated approximately in `DynNumber` instead.
+///
+/// Returns flags raised by the approximation as well.
+pub fn evaluate_approximately_with(
+    node: &SpanWrapper<Node>,
+    config: &Config,
+) -> (IResult<DynNumber>, Status) {
+    match evaluate_with::<Rational>(node, config) {
+        Ok(num) => {
+            let (res, status) = Status::capture(|| approximate_with(&num, config));
+            (Ok(res), status)
+        }
+        Err(err) if is_inexact_err(&err) => evaluate_with_status(node, config),
+        Err(err) => (Err(err), Status::default()),
+    }
+}
+
+/// Checks if error is raised by evaluation that has no exact result.
+fn is_inexact_err(err: &SpanWrapper<String>) -> bool {
+    let (msg, _) = err.borrow_to_tuple();
+
+    [ERR__INEXACT_FN, ERR__INEXACT_CONST, ERR__INEXACT_POW].contains(&msg.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval(expr: &str) -> Result<String, (String, Span)> {
+        let ast = parse::parse(expr).map_err(|err| err.to_tuple())?;
+
+        evaluate::<Dec64>(&ast)
+            .map(|num| num.to_string())
+            .map_err(|err| err.to_tuple())
+    }
+
+    #[test]
+    fn arithmetic() {
+        let cases = [
+            ("1 + 2 * 3", "7"),
+            ("(1 + 2) * 3", "9"),
+            ("10 - 4 - 3", "3"),
+            ("12 / 4 / 3", "1"),
+            ("1 / 4", "0.25"),
+            ("-(2 - 5)", "3"),
+        ];
+
+        for (expr, expected) in cases {
+            assert_eq!(eval(expr).as_deref(), Ok(expected), "{}", expr);
+        }
+    }
+
+    #[test]
+    fn pow_is_right_associative_and_binds_tighter_than_neg() {
+        let cases = [
+            ("2^3^2", "512"),
+            ("(2^3)^2", "64"),
+            ("-2^2", "-4"),
+            ("(-2)^2", "4"),
+            ("2^-1", "0.5"),
+            ("2 * 3^2", "18"),
+        ];
+
+        for (expr, expected) in cases {
+            assert_eq!(eval(expr).as_deref(), Ok(expected), "{}", expr);
+        }
+    }
+
+    #[test]
+    fn floor_div_and_mod() {
+        let cases = [
+            ("7 // 2", "3"),
+            ("-7 // 2", "-4"),
+            ("7 // -2", "-4"),
+            ("7 % 3", "1"),
+            ("-7 % 3", "2"),
+            ("7 % -3", "-2"),
+            ("7.5 % 2", "1.5"),
+            ("1 + 7 // 2 * 2", "7"),
+        ];
+
+        for (expr, expected) in cases {
+            assert_eq!(eval(expr).as_deref(), Ok(expected), "{}", expr);
+        }
+    }
+
+    #[test]
+    fn invalid_number_is_reported_with_its_span() {
+        let (msg, span) = eval("1 + 1e").unwrap_err();
+
+        assert!(msg.starts_with(ERR__INVALID_NUMBER), "{}", msg);
+        assert_eq!((span.start(), span.end()), (4, 6));
     }
 }
@@ -0,0 +1,742 @@
+use number::{AngleUnit, DynNumber, Number, Rational, TryFromStrError};
+use std::num::ParseFloatError;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// Number type that expressions are evaluated in.
+///
+/// Math functions and constants return `None` if the type cannot represent their results,
+/// e.g. irrational results of exact numbers.
+pub trait Numeric:
+    Sized
+    + Clone
+    + Default
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    type ParseError: ToString;
+
+    /// Parses number literal.
+    fn from_literal(src: &str) -> Result<Self, Self::ParseError>;
+
+    /// Formats number for output.
+    fn format(&self) -> String;
+
+    fn nan() -> Self;
+    fn inf() -> Self;
+    fn is_nan(&self) -> bool;
+    fn is_infinite(&self) -> bool;
+
+    fn abs(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn div_floor(self, rhs: Self) -> Self;
+    fn modulo(self, rhs: Self) -> Self;
+    fn pow(self, exp: Self) -> Option<Self>;
+
+    fn sqrt(self) -> Option<Self> {
+        None
+    }
+
+    fn nth_root(self, _n: Self) -> Option<Self> {
+        None
+    }
+
+    fn exp(self) -> Option<Self> {
+        None
+    }
+
+    fn ln(self) -> Option<Self> {
+        None
+    }
+
+    fn log10(self) -> Option<Self> {
+        None
+    }
+
+    fn log(self, _base: Self) -> Option<Self> {
+        None
+    }
+
+    fn sin(self, _unit: AngleUnit) -> Option<Self> {
+        None
+    }
+
+    fn cos(self, _unit: AngleUnit) -> Option<Self> {
+        None
+    }
+
+    fn tan(self, _unit: AngleUnit) -> Option<Self> {
+        None
+    }
+
+    fn asin(self, _unit: AngleUnit) -> Option<Self> {
+        None
+    }
+
+    fn acos(self, _unit: AngleUnit) -> Option<Self> {
+        None
+    }
+
+    fn atan(self, _unit: AngleUnit) -> Option<Self> {
+        None
+    }
+
+    fn atan2(self, _x: Self, _unit: AngleUnit) -> Option<Self> {
+        None
+    }
+
+    fn sinh(self) -> Option<Self> {
+        None
+    }
+
+    fn cosh(self) -> Option<Self> {
+        None
+    }
+
+    fn tanh(self) -> Option<Self> {
+        None
+    }
+
+    fn asinh(self) -> Option<Self> {
+        None
+    }
+
+    fn acosh(self) -> Option<Self> {
+        None
+    }
+
+    fn atanh(self) -> Option<Self> {
+        None
+    }
+
+    fn pi() -> Option<Self> {
+        None
+    }
+
+    fn tau() -> Option<Self> {
+        None
+    }
+
+    fn e() -> Option<Self> {
+        None
+    }
+
+    fn phi() -> Option<Self> {
+        None
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> Numeric for Number<RADIX, PREC> {
+    type ParseError = TryFromStrError<RADIX, PREC>;
+
+    fn from_literal(src: &str) -> Result<Self, Self::ParseError> {
+        Self::try_from(src)
+    }
+
+    fn format(&self) -> String {
+        self.to_string()
+    }
+
+    fn nan() -> Self {
+        Number::nan()
+    }
+
+    fn inf() -> Self {
+        Number::inf()
+    }
+
+    fn is_nan(&self) -> bool {
+        Number::is_nan(self)
+    }
+
+    fn is_infinite(&self) -> bool {
+        Number::is_infinite(self)
+    }
+
+    fn abs(self) -> Self {
+        Number::abs(self)
+    }
+
+    fn floor(self) -> Self {
+        Number::floor(self)
+    }
+
+    fn ceil(self) -> Self {
+        Number::ceil(self)
+    }
+
+    fn round(self) -> Self {
+        Number::round(self)
+    }
+
+    fn div_floor(self, rhs: Self) -> Self {
+        Number::div_floor(self, rhs)
+    }
+
+    fn modulo(self, rhs: Self) -> Self {
+        Number::modulo(self, rhs)
+    }
+
+    fn pow(self, exp: Self) -> Option<Self> {
+        Some(Number::pow(self, exp))
+    }
+
+    fn sqrt(self) -> Option<Self> {
+        Some(Number::sqrt(self))
+    }
+
+    fn nth_root(self, n: Self) -> Option<Self> {
+        Some(Number::nth_root(self, n))
+    }
+
+    fn exp(self) -> Option<Self> {
+        Some(Number::exp(self))
+    }
+
+    fn ln(self) -> Option<Self> {
+        Some(Number::ln(self))
+    }
+
+    fn log10(self) -> Option<Self> {
+        Some(Number::log10(self))
+    }
+
+    fn log(self, base: Self) -> Option<Self> {
+        Some(Number::log(self, base))
+    }
+
+    fn sin(self, unit: AngleUnit) -> Option<Self> {
+        Some(self.sin_in(unit))
+    }
+
+    fn cos(self, unit: AngleUnit) -> Option<Self> {
+        Some(self.cos_in(unit))
+    }
+
+    fn tan(self, unit: AngleUnit) -> Option<Self> {
+        Some(self.tan_in(unit))
+    }
+
+    fn asin(self, unit: AngleUnit) -> Option<Self> {
+        Some(self.asin_in(unit))
+    }
+
+    fn acos(self, unit: AngleUnit) -> Option<Self> {
+        Some(self.acos_in(unit))
+    }
+
+    fn atan(self, unit: AngleUnit) -> Option<Self> {
+        Some(self.atan_in(unit))
+    }
+
+    fn atan2(self, x: Self, unit: AngleUnit) -> Option<Self> {
+        Some(self.atan2_in(x, unit))
+    }
+
+    fn sinh(self) -> Option<Self> {
+        Some(Number::sinh(self))
+    }
+
+    fn cosh(self) -> Option<Self> {
+        Some(Number::cosh(self))
+    }
+
+    fn tanh(self) -> Option<Self> {
+        Some(Number::tanh(self))
+    }
+
+    fn asinh(self) -> Option<Self> {
+        Some(Number::asinh(self))
+    }
+
+    fn acosh(self) -> Option<Self> {
+        Some(Number::acosh(self))
+    }
+
+    fn atanh(self) -> Option<Self> {
+        Some(Number::atanh(self))
+    }
+
+    fn pi() -> Option<Self> {
+        Some(Number::pi())
+    }
+
+    fn tau() -> Option<Self> {
+        Some(Number::tau())
+    }
+
+    fn e() -> Option<Self> {
+        Some(Number::e())
+    }
+
+    fn phi() -> Option<Self> {
+        Some(Number::phi())
+    }
+}
+
+impl Numeric for DynNumber {
+    type ParseError = String;
+
+    fn from_literal(src: &str) -> Result<Self, Self::ParseError> {
+        Self::try_from(src)
+    }
+
+    fn format(&self) -> String {
+        self.to_string()
+    }
+
+    fn nan() -> Self {
+        DynNumber::nan()
+    }
+
+    fn inf() -> Self {
+        DynNumber::inf()
+    }
+
+    fn is_nan(&self) -> bool {
+        DynNumber::is_nan(self)
+    }
+
+    fn is_infinite(&self) -> bool {
+        DynNumber::is_infinite(self)
+    }
+
+    fn abs(self) -> Self {
+        DynNumber::abs(self)
+    }
+
+    fn floor(self) -> Self {
+        DynNumber::floor(self)
+    }
+
+    fn ceil(self) -> Self {
+        DynNumber::ceil(self)
+    }
+
+    fn round(self) -> Self {
+        DynNumber::round(self)
+    }
+
+    fn div_floor(self, rhs: Self) -> Self {
+        DynNumber::div_floor(self, rhs)
+    }
+
+    fn modulo(self, rhs: Self) -> Self {
+        DynNumber::modulo(self, rhs)
+    }
+
+    fn pow(self, exp: Self) -> Option<Self> {
+        Some(DynNumber::pow(self, exp))
+    }
+
+    fn sqrt(self) -> Option<Self> {
+        Some(DynNumber::sqrt(self))
+    }
+
+    fn nth_root(self, n: Self) -> Option<Self> {
+        Some(DynNumber::nth_root(self, n))
+    }
+
+    fn exp(self) -> Option<Self> {
+        Some(DynNumber::exp(self))
+    }
+
+    fn ln(self) -> Option<Self> {
+        Some(DynNumber::ln(self))
+    }
+
+    fn log10(self) -> Option<Self> {
+        Some(DynNumber::log10(self))
+    }
+
+    fn log(self, base: Self) -> Option<Self> {
+        Some(DynNumber::log(self, base))
+    }
+
+    fn sin(self, unit: AngleUnit) -> Option<Self> {
+        Some(self.sin_in(unit))
+    }
+
+    fn cos(self, unit: AngleUnit) -> Option<Self> {
+        Some(self.cos_in(unit))
+    }
+
+    fn tan(self, unit: AngleUnit) -> Option<Self> {
+        Some(self.tan_in(unit))
+    }
+
+    fn asin(self, unit: AngleUnit) -> Option<Self> {
+        Some(self.asin_in(unit))
+    }
+
+    fn acos(self, unit: AngleUnit) -> Option<Self> {
+        Some(self.acos_in(unit))
+    }
+
+    fn atan(self, unit: AngleUnit) -> Option<Self> {
+        Some(self.atan_in(unit))
+    }
+
+    fn atan2(self, x: Self, unit: AngleUnit) -> Option<Self> {
+        Some(self.atan2_in(x, unit))
+    }
+
+    fn sinh(self) -> Option<Self> {
+        Some(DynNumber::sinh(self))
+    }
+
+    fn cosh(self) -> Option<Self> {
+        Some(DynNumber::cosh(self))
+    }
+
+    fn tanh(self) -> Option<Self> {
+        Some(DynNumber::tanh(self))
+    }
+
+    fn asinh(self) -> Option<Self> {
+        Some(DynNumber::asinh(self))
+    }
+
+    fn acosh(self) -> Option<Self> {
+        Some(DynNumber::acosh(self))
+    }
+
+    fn atanh(self) -> Option<Self> {
+        Some(DynNumber::atanh(self))
+    }
+
+    fn pi() -> Option<Self> {
+        Some(DynNumber::pi())
+    }
+
+    fn tau() -> Option<Self> {
+        Some(DynNumber::tau())
+    }
+
+    fn e() -> Option<Self> {
+        Some(DynNumber::e())
+    }
+
+    fn phi() -> Option<Self> {
+        Some(DynNumber::phi())
+    }
+}
+
+/// Converts angle in given unit to radians.
+fn f64_to_radians(num: f64, unit: AngleUnit) -> f64 {
+    match unit {
+        AngleUnit::Radians => num,
+        AngleUnit::Degrees => num.to_radians(),
+    }
+}
+
+/// Converts angle in radians to given unit.
+fn f64_from_radians(num: f64, unit: AngleUnit) -> f64 {
+    match unit {
+        AngleUnit::Radians => num,
+        AngleUnit::Degrees => num.to_degrees(),
+    }
+}
+
+impl Numeric for f64 {
+    type ParseError = ParseFloatError;
+
+    fn from_literal(src: &str) -> Result<Self, Self::ParseError> {
+        src.parse()
+    }
+
+    fn format(&self) -> String {
+        self.to_string()
+    }
+
+    fn nan() -> Self {
+        f64::NAN
+    }
+
+    fn inf() -> Self {
+        f64::INFINITY
+    }
+
+    fn is_nan(&self) -> bool {
+        f64::is_nan(*self)
+    }
+
+    fn is_infinite(&self) -> bool {
+        f64::is_infinite(*self)
+    }
+
+    fn abs(self) -> Self {
+        f64::abs(self)
+    }
+
+    fn floor(self) -> Self {
+        f64::floor(self)
+    }
+
+    fn ceil(self) -> Self {
+        f64::ceil(self)
+    }
+
+    fn round(self) -> Self {
+        f64::round(self)
+    }
+
+    fn div_floor(self, rhs: Self) -> Self {
+        (self / rhs).floor()
+    }
+
+    /// Computes remainder of floor division, it has the sign of divisor.
+    fn modulo(self, rhs: Self) -> Self {
+        let rem = self % rhs;
+
+        match rem != 0.0 && rem.is_sign_negative() != rhs.is_sign_negative() {
+            true => rem + rhs,
+            false => rem,
+        }
+    }
+
+    fn pow(self, exp: Self) -> Option<Self> {
+        Some(self.powf(exp))
+    }
+
+    fn sqrt(self) -> Option<Self> {
+        Some(f64::sqrt(self))
+    }
+
+    /// Root of negative number is negative if `n` is an odd integer, as for `Number`.
+    fn nth_root(self, n: Self) -> Option<Self> {
+        let is_odd_int = n.fract() == 0.0 && n % 2.0 != 0.0;
+
+        match self < 0.0 && is_odd_int {
+            true => Some(-(-self).powf(n.recip())),
+            false => Some(self.powf(n.recip())),
+        }
+    }
+
+    fn exp(self) -> Option<Self> {
+        Some(f64::exp(self))
+    }
+
+    fn ln(self) -> Option<Self> {
+        Some(f64::ln(self))
+    }
+
+    fn log10(self) -> Option<Self> {
+        Some(f64::log10(self))
+    }
+
+    fn log(self, base: Self) -> Option<Self> {
+        Some(f64::ln(self) / f64::ln(base))
+    }
+
+    fn sin(self, unit: AngleUnit) -> Option<Self> {
+        Some(f64_to_radians(self, unit).sin())
+    }
+
+    fn cos(self, unit: AngleUnit) -> Option<Self> {
+        Some(f64_to_radians(self, unit).cos())
+    }
+
+    fn tan(self, unit: AngleUnit) -> Option<Self> {
+        Some(f64_to_radians(self, unit).tan())
+    }
+
+    fn asin(self, unit: AngleUnit) -> Option<Self> {
+        Some(f64_from_radians(f64::asin(self), unit))
+    }
+
+    fn acos(self, unit: AngleUnit) -> Option<Self> {
+        Some(f64_from_radians(f64::acos(self), unit))
+    }
+
+    fn atan(self, unit: AngleUnit) -> Option<Self> {
+        Some(f64_from_radians(f64::atan(self), unit))
+    }
+
+    fn atan2(self, x: Self, unit: AngleUnit) -> Option<Self> {
+        Some(f64_from_radians(f64::atan2(self, x), unit))
+    }
+
+    fn sinh(self) -> Option<Self> {
+        Some(f64::sinh(self))
+    }
+
+    fn cosh(self) -> Option<Self> {
+        Some(f64::cosh(self))
+    }
+
+    fn tanh(self) -> Option<Self> {
+        Some(f64::tanh(self))
+    }
+
+    fn asinh(self) -> Option<Self> {
+        Some(f64::asinh(self))
+    }
+
+    fn acosh(self) -> Option<Self> {
+        Some(f64::acosh(self))
+    }
+
+    fn atanh(self) -> Option<Self> {
+        Some(f64::atanh(self))
+    }
+
+    fn pi() -> Option<Self> {
+        Some(std::f64::consts::PI)
+    }
+
+    fn tau() -> Option<Self> {
+        Some(std::f64::consts::TAU)
+    }
+
+    fn e() -> Option<Self> {
+        Some(std::f64::consts::E)
+    }
+
+    fn phi() -> Option<Self> {
+        Some((1.0 + 5f64.sqrt()) / 2.0)
+    }
+}
+
+/// Exact numbers have no irrational functions and constants,
+/// powers and roots are exact only if they are rational.
+impl Numeric for Rational {
+    type ParseError = TryFromStrError<10, 1>;
+
+    fn from_literal(src: &str) -> Result<Self, Self::ParseError> {
+        Self::try_from(src)
+    }
+
+    fn format(&self) -> String {
+        self.to_string()
+    }
+
+    fn nan() -> Self {
+        Rational::nan()
+    }
+
+    fn inf() -> Self {
+        Rational::inf()
+    }
+
+    fn is_nan(&self) -> bool {
+        Rational::is_nan(self)
+    }
+
+    fn is_infinite(&self) -> bool {
+        Rational::is_infinite(self)
+    }
+
+    fn abs(self) -> Self {
+        Rational::abs(self)
+    }
+
+    fn floor(self) -> Self {
+        Rational::floor(self)
+    }
+
+    fn ceil(self) -> Self {
+        Rational::ceil(self)
+    }
+
+    fn round(self) -> Self {
+        Rational::round(self)
+    }
+
+    fn div_floor(self, rhs: Self) -> Self {
+        Rational::div_floor(self, rhs)
+    }
+
+    fn modulo(self, rhs: Self) -> Self {
+        Rational::modulo(self, rhs)
+    }
+
+    fn pow(self, exp: Self) -> Option<Self> {
+        Rational::pow_ratio(self, exp)
+    }
+
+    fn sqrt(self) -> Option<Self> {
+        Rational::sqrt(self)
+    }
+
+    fn nth_root(self, n: Self) -> Option<Self> {
+        Rational::nth_root(self, n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rational(src: &str) -> Rational {
+        Rational::from_literal(src).unwrap_or_else(|err| panic!("{}: {}", src, err.to_string()))
+    }
+
+    #[test]
+    fn rational_is_exact() {
+        let third = rational("1") / rational("3");
+
+        assert_eq!((rational("0.1") + rational("0.2")).format(), "3/10");
+        assert_eq!(third.format(), "1/3");
+        assert_eq!(Numeric::round(rational("-2.5")).format(), "-3");
+        assert_eq!(Numeric::floor(rational("-2.5")).format(), "-3");
+        assert_eq!(Numeric::ceil(rational("-2.5")).format(), "-2");
+        assert_eq!(
+            Numeric::div_floor(rational("-7"), rational("2")).format(),
+            "-4"
+        );
+        assert_eq!(Numeric::modulo(rational("-7"), rational("3")).format(), "2");
+        assert!(Numeric::is_nan(&(rational("0") / rational("0"))));
+        assert!(Numeric::is_infinite(&(rational("1") / rational("0"))));
+    }
+
+    #[test]
+    fn rational_has_only_rational_results() {
+        let two_thirds = rational("2") / rational("3");
+        let format = |num: Option<Rational>| num.map(|num| num.format());
+
+        assert_eq!(
+            format(Numeric::pow(rational("8"), two_thirds)),
+            Some("4".into())
+        );
+        assert_eq!(
+            format(Numeric::pow(rational("2"), rational("-2"))),
+            Some("1/4".into())
+        );
+        assert_eq!(format(Numeric::pow(rational("2"), rational("0.5"))), None);
+        assert_eq!(format(Numeric::sqrt(rational("0.25"))), Some("1/2".into()));
+        assert_eq!(
+            format(Numeric::nth_root(rational("-8"), rational("3"))),
+            Some("-2".into())
+        );
+        assert_eq!(format(Numeric::sqrt(rational("2"))), None);
+        assert_eq!(format(Numeric::exp(rational("1"))), None);
+        assert_eq!(
+            format(Numeric::sin(rational("1"), AngleUnit::Radians)),
+            None
+        );
+        assert_eq!(format(<Rational as Numeric>::pi()), None);
+    }
+
+    #[test]
+    fn f64_functions() {
+        assert_eq!(f64::from_literal("1.5e2"), Ok(150.0));
+        assert!(f64::from_literal("1.5.2").is_err());
+        assert_eq!(Numeric::div_floor(7.0, -2.0), -4.0);
+        assert_eq!(Numeric::modulo(-7.0, 3.0), 2.0);
+        assert_eq!(Numeric::modulo(7.0, -3.0), -2.0);
+        assert_eq!(Numeric::modulo(6.0, 3.0), 0.0);
+        assert_eq!(Numeric::nth_root(-8.0, 3.0), Some(-2.0));
+        assert!(Numeric::nth_root(-8.0, 2.0).is_some_and(f64::is_nan));
+        assert_eq!(Numeric::sin(90.0, AngleUnit::Degrees), Some(1.0));
+        assert_eq!(Numeric::atan2(1.0, 0.0, AngleUnit::Degrees), Some(90.0));
+        assert_eq!(<f64 as Numeric>::pi(), Some(std::f64::consts::PI));
+        assert_eq!(<f64 as Numeric>::nan().format(), "NaN");
+        assert_eq!(<f64 as Numeric>::inf().format(), "inf");
+    }
+}
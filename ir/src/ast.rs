@@ -1,12 +1,15 @@
 use std::cmp::Ordering;
 use crate::span::SpanWrapper;
 
-const NEG_OP_PRIORITY: usize = 1;
+const NEG_OP_PRIORITY: usize = 3;
 
 const ADD_OP_PRIORITY: usize = 1;
 const SUB_OP_PRIORITY: usize = 1;
 const DIV_OP_PRIORITY: usize = 2;
 const MUL_OP_PRIORITY: usize = 2;
+const FLOOR_DIV_OP_PRIORITY: usize = 2;
+const MOD_OP_PRIORITY: usize = 2;
+const POW_OP_PRIORITY: usize = 4;
 
 #[derive(Clone, Debug)]
 pub enum UnOpKind {
@@ -14,7 +17,7 @@ pub enum UnOpKind {
 }
 
 impl UnOpKind {
-    fn priority(&self) -> usize {
+    pub fn priority(&self) -> usize {
         match self {
             Self::Neg => NEG_OP_PRIORITY,
         }
@@ -47,17 +50,27 @@ pub enum BinOpKind {
     Sub,
     Mul,
     Div,
+    FloorDiv,
+    Mod,
+    Pow,
 }
 
 impl BinOpKind {
-    fn priority(&self) -> usize {
+    pub fn priority(&self) -> usize {
         match self {
             Self::Add => ADD_OP_PRIORITY,
             Self::Sub => SUB_OP_PRIORITY,
             Self::Mul => MUL_OP_PRIORITY,
             Self::Div => DIV_OP_PRIORITY,
+            Self::FloorDiv => FLOOR_DIV_OP_PRIORITY,
+            Self::Mod => MOD_OP_PRIORITY,
+            Self::Pow => POW_OP_PRIORITY,
         }
     }
+
+    pub fn is_right_assoc(&self) -> bool {
+        matches!(self, Self::Pow)
+    }
 }
 
 impl PartialOrd for BinOpKind {
@@ -83,6 +96,7 @@ impl Eq for BinOpKind {}
 #[derive(Clone, Debug)]
 pub enum Node {
     Num(String),
+    Const(String),
     BinOp(BinOpKind, Box<SpanWrapper<Node>>, Box<SpanWrapper<Node>>),
     UnOp(UnOpKind, Box<SpanWrapper<Node>>),
     Fn(String, Vec<SpanWrapper<Node>>),
@@ -4,8 +4,11 @@ use crate::span::{SharedSpanWrapper, SpanWrapper};
 pub enum LitKind {
     Asterisk,
     Slash,
+    DoubleSlash,
+    Percent,
     Plus,
     Hyphen,
+    Caret,
     Comma,
 }
 
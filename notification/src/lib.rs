@@ -41,15 +41,17 @@ impl Notification {
     }
 
     fn split_to_lines(string: String) -> Vec<String> {
-        let termsize::Size {
-            cols: term_width, ..
-        } = termsize::get().unwrap();
+        // Output that is not a terminal or has unknown width is not wrapped
+        let term_width = match termsize::get() {
+            Some(size) if size.cols > 0 => size.cols as usize,
+            _ => usize::MAX,
+        };
 
         let mut lines = Vec::new();
         let mut line_start = 0;
 
         for (pos, sym) in string.chars().enumerate() {
-            if sym != '\n' && pos - line_start != term_width as usize {
+            if sym != '\n' && pos - line_start != term_width {
                 continue;
             }
 
@@ -119,3 +121,20 @@ impl std::fmt::Display for Notification {
         write!(f, "")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_without_terminal() {
+        colored::control::set_override(false);
+
+        let expr = String::from("1 @ 2");
+        let err = Notification::new_error(&expr, "Unknown symbols.".to_string(), 2, 3);
+        let output = err.to_string();
+
+        assert!(output.starts_with("Error: Unknown symbols.\n"));
+        assert!(output.contains("| 1 @ 2\n|   ^  \n"));
+    }
+}
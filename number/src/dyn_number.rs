@@ -0,0 +1,777 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::finite_ops::fit_to_prec;
+use crate::finite_trig::AngleUnit;
+use crate::number::{Number, NumberKind, OverflowToInf};
+use crate::number_checked::ArithmeticError;
+use crate::number_format::NumberFormat;
+use crate::number_spec::{DynPrecNumber, NumberSpec};
+use crate::rational::Rational;
+use crate::rounding::RoundingMode;
+use crate::total_order::TotalOrder;
+
+/// Number of radix known at runtime, its precision follows the current `NumberSpec`.
+#[derive(Clone, Debug)]
+enum DynValue {
+    R2(DynPrecNumber<2>),
+    R3(DynPrecNumber<3>),
+    R4(DynPrecNumber<4>),
+    R5(DynPrecNumber<5>),
+    R6(DynPrecNumber<6>),
+    R7(DynPrecNumber<7>),
+    R8(DynPrecNumber<8>),
+    R9(DynPrecNumber<9>),
+    R10(DynPrecNumber<10>),
+    R11(DynPrecNumber<11>),
+    R12(DynPrecNumber<12>),
+    R13(DynPrecNumber<13>),
+    R14(DynPrecNumber<14>),
+    R15(DynPrecNumber<15>),
+    R16(DynPrecNumber<16>),
+    R17(DynPrecNumber<17>),
+    R18(DynPrecNumber<18>),
+    R19(DynPrecNumber<19>),
+    R20(DynPrecNumber<20>),
+    R21(DynPrecNumber<21>),
+    R22(DynPrecNumber<22>),
+    R23(DynPrecNumber<23>),
+    R24(DynPrecNumber<24>),
+    R25(DynPrecNumber<25>),
+    R26(DynPrecNumber<26>),
+    R27(DynPrecNumber<27>),
+    R28(DynPrecNumber<28>),
+    R29(DynPrecNumber<29>),
+    R30(DynPrecNumber<30>),
+    R31(DynPrecNumber<31>),
+    R32(DynPrecNumber<32>),
+    R33(DynPrecNumber<33>),
+    R34(DynPrecNumber<34>),
+    R35(DynPrecNumber<35>),
+    R36(DynPrecNumber<36>),
+}
+
+/// Applies `$res` to number of any radix and wraps result into the same radix.
+macro_rules! map_value {
+    ($value:expr, $num:ident => $res:expr) => {
+        match $value {
+            DynValue::R2($num) => DynValue::R2($res),
+            DynValue::R3($num) => DynValue::R3($res),
+            DynValue::R4($num) => DynValue::R4($res),
+            DynValue::R5($num) => DynValue::R5($res),
+            DynValue::R6($num) => DynValue::R6($res),
+            DynValue::R7($num) => DynValue::R7($res),
+            DynValue::R8($num) => DynValue::R8($res),
+            DynValue::R9($num) => DynValue::R9($res),
+            DynValue::R10($num) => DynValue::R10($res),
+            DynValue::R11($num) => DynValue::R11($res),
+            DynValue::R12($num) => DynValue::R12($res),
+            DynValue::R13($num) => DynValue::R13($res),
+            DynValue::R14($num) => DynValue::R14($res),
+            DynValue::R15($num) => DynValue::R15($res),
+            DynValue::R16($num) => DynValue::R16($res),
+            DynValue::R17($num) => DynValue::R17($res),
+            DynValue::R18($num) => DynValue::R18($res),
+            DynValue::R19($num) => DynValue::R19($res),
+            DynValue::R20($num) => DynValue::R20($res),
+            DynValue::R21($num) => DynValue::R21($res),
+            DynValue::R22($num) => DynValue::R22($res),
+            DynValue::R23($num) => DynValue::R23($res),
+            DynValue::R24($num) => DynValue::R24($res),
+            DynValue::R25($num) => DynValue::R25($res),
+            DynValue::R26($num) => DynValue::R26($res),
+            DynValue::R27($num) => DynValue::R27($res),
+            DynValue::R28($num) => DynValue::R28($res),
+            DynValue::R29($num) => DynValue::R29($res),
+            DynValue::R30($num) => DynValue::R30($res),
+            DynValue::R31($num) => DynValue::R31($res),
+            DynValue::R32($num) => DynValue::R32($res),
+            DynValue::R33($num) => DynValue::R33($res),
+            DynValue::R34($num) => DynValue::R34($res),
+            DynValue::R35($num) => DynValue::R35($res),
+            DynValue::R36($num) => DynValue::R36($res),
+        }
+    };
+}
+
+/// Applies `$res` to number of any radix.
+macro_rules! with_value {
+    ($value:expr, $num:ident => $res:expr) => {
+        match $value {
+            DynValue::R2($num) => $res,
+            DynValue::R3($num) => $res,
+            DynValue::R4($num) => $res,
+            DynValue::R5($num) => $res,
+            DynValue::R6($num) => $res,
+            DynValue::R7($num) => $res,
+            DynValue::R8($num) => $res,
+            DynValue::R9($num) => $res,
+            DynValue::R10($num) => $res,
+            DynValue::R11($num) => $res,
+            DynValue::R12($num) => $res,
+            DynValue::R13($num) => $res,
+            DynValue::R14($num) => $res,
+            DynValue::R15($num) => $res,
+            DynValue::R16($num) => $res,
+            DynValue::R17($num) => $res,
+            DynValue::R18($num) => $res,
+            DynValue::R19($num) => $res,
+            DynValue::R20($num) => $res,
+            DynValue::R21($num) => $res,
+            DynValue::R22($num) => $res,
+            DynValue::R23($num) => $res,
+            DynValue::R24($num) => $res,
+            DynValue::R25($num) => $res,
+            DynValue::R26($num) => $res,
+            DynValue::R27($num) => $res,
+            DynValue::R28($num) => $res,
+            DynValue::R29($num) => $res,
+            DynValue::R30($num) => $res,
+            DynValue::R31($num) => $res,
+            DynValue::R32($num) => $res,
+            DynValue::R33($num) => $res,
+            DynValue::R34($num) => $res,
+            DynValue::R35($num) => $res,
+            DynValue::R36($num) => $res,
+        }
+    };
+}
+
+/// Applies `$res` to numbers of the same radix and wraps result into the radix.
+macro_rules! map_values {
+    ($lhs:expr, $rhs:expr, $lhs_num:ident, $rhs_num:ident => $res:expr) => {
+        match ($lhs, $rhs) {
+            (DynValue::R2($lhs_num), DynValue::R2($rhs_num)) => DynValue::R2($res),
+            (DynValue::R3($lhs_num), DynValue::R3($rhs_num)) => DynValue::R3($res),
+            (DynValue::R4($lhs_num), DynValue::R4($rhs_num)) => DynValue::R4($res),
+            (DynValue::R5($lhs_num), DynValue::R5($rhs_num)) => DynValue::R5($res),
+            (DynValue::R6($lhs_num), DynValue::R6($rhs_num)) => DynValue::R6($res),
+            (DynValue::R7($lhs_num), DynValue::R7($rhs_num)) => DynValue::R7($res),
+            (DynValue::R8($lhs_num), DynValue::R8($rhs_num)) => DynValue::R8($res),
+            (DynValue::R9($lhs_num), DynValue::R9($rhs_num)) => DynValue::R9($res),
+            (DynValue::R10($lhs_num), DynValue::R10($rhs_num)) => DynValue::R10($res),
+            (DynValue::R11($lhs_num), DynValue::R11($rhs_num)) => DynValue::R11($res),
+            (DynValue::R12($lhs_num), DynValue::R12($rhs_num)) => DynValue::R12($res),
+            (DynValue::R13($lhs_num), DynValue::R13($rhs_num)) => DynValue::R13($res),
+            (DynValue::R14($lhs_num), DynValue::R14($rhs_num)) => DynValue::R14($res),
+            (DynValue::R15($lhs_num), DynValue::R15($rhs_num)) => DynValue::R15($res),
+            (DynValue::R16($lhs_num), DynValue::R16($rhs_num)) => DynValue::R16($res),
+            (DynValue::R17($lhs_num), DynValue::R17($rhs_num)) => DynValue::R17($res),
+            (DynValue::R18($lhs_num), DynValue::R18($rhs_num)) => DynValue::R18($res),
+            (DynValue::R19($lhs_num), DynValue::R19($rhs_num)) => DynValue::R19($res),
+            (DynValue::R20($lhs_num), DynValue::R20($rhs_num)) => DynValue::R20($res),
+            (DynValue::R21($lhs_num), DynValue::R21($rhs_num)) => DynValue::R21($res),
+            (DynValue::R22($lhs_num), DynValue::R22($rhs_num)) => DynValue::R22($res),
+            (DynValue::R23($lhs_num), DynValue::R23($rhs_num)) => DynValue::R23($res),
+            (DynValue::R24($lhs_num), DynValue::R24($rhs_num)) => DynValue::R24($res),
+            (DynValue::R25($lhs_num), DynValue::R25($rhs_num)) => DynValue::R25($res),
+            (DynValue::R26($lhs_num), DynValue::R26($rhs_num)) => DynValue::R26($res),
+            (DynValue::R27($lhs_num), DynValue::R27($rhs_num)) => DynValue::R27($res),
+            (DynValue::R28($lhs_num), DynValue::R28($rhs_num)) => DynValue::R28($res),
+            (DynValue::R29($lhs_num), DynValue::R29($rhs_num)) => DynValue::R29($res),
+            (DynValue::R30($lhs_num), DynValue::R30($rhs_num)) => DynValue::R30($res),
+            (DynValue::R31($lhs_num), DynValue::R31($rhs_num)) => DynValue::R31($res),
+            (DynValue::R32($lhs_num), DynValue::R32($rhs_num)) => DynValue::R32($res),
+            (DynValue::R33($lhs_num), DynValue::R33($rhs_num)) => DynValue::R33($res),
+            (DynValue::R34($lhs_num), DynValue::R34($rhs_num)) => DynValue::R34($res),
+            (DynValue::R35($lhs_num), DynValue::R35($rhs_num)) => DynValue::R35($res),
+            (DynValue::R36($lhs_num), DynValue::R36($rhs_num)) => DynValue::R36($res),
+            _ => unreachable!("operands are aligned to the same radix"),
+        }
+    };
+}
+
+/// Builds number of given radix from `$res`, that is generic over radix.
+macro_rules! value_of_radix {
+    ($radix:expr, $res:expr) => {
+        match $radix {
+            2 => DynValue::R2($res),
+            3 => DynValue::R3($res),
+            4 => DynValue::R4($res),
+            5 => DynValue::R5($res),
+            6 => DynValue::R6($res),
+            7 => DynValue::R7($res),
+            8 => DynValue::R8($res),
+            9 => DynValue::R9($res),
+            10 => DynValue::R10($res),
+            11 => DynValue::R11($res),
+            12 => DynValue::R12($res),
+            13 => DynValue::R13($res),
+            14 => DynValue::R14($res),
+            15 => DynValue::R15($res),
+            16 => DynValue::R16($res),
+            17 => DynValue::R17($res),
+            18 => DynValue::R18($res),
+            19 => DynValue::R19($res),
+            20 => DynValue::R20($res),
+            21 => DynValue::R21($res),
+            22 => DynValue::R22($res),
+            23 => DynValue::R23($res),
+            24 => DynValue::R24($res),
+            25 => DynValue::R25($res),
+            26 => DynValue::R26($res),
+            27 => DynValue::R27($res),
+            28 => DynValue::R28($res),
+            29 => DynValue::R29($res),
+            30 => DynValue::R30($res),
+            31 => DynValue::R31($res),
+            32 => DynValue::R32($res),
+            33 => DynValue::R33($res),
+            34 => DynValue::R34($res),
+            35 => DynValue::R35($res),
+            36 => DynValue::R36($res),
+            radix => panic!("inadmissable radix {}", radix),
+        }
+    };
+}
+
+/// Applies `$res` to numbers of the same radix.
+macro_rules! with_values {
+    ($lhs:expr, $rhs:expr, $lhs_num:ident, $rhs_num:ident => $res:expr) => {
+        match ($lhs, $rhs) {
+            (DynValue::R2($lhs_num), DynValue::R2($rhs_num)) => $res,
+            (DynValue::R3($lhs_num), DynValue::R3($rhs_num)) => $res,
+            (DynValue::R4($lhs_num), DynValue::R4($rhs_num)) => $res,
+            (DynValue::R5($lhs_num), DynValue::R5($rhs_num)) => $res,
+            (DynValue::R6($lhs_num), DynValue::R6($rhs_num)) => $res,
+            (DynValue::R7($lhs_num), DynValue::R7($rhs_num)) => $res,
+            (DynValue::R8($lhs_num), DynValue::R8($rhs_num)) => $res,
+            (DynValue::R9($lhs_num), DynValue::R9($rhs_num)) => $res,
+            (DynValue::R10($lhs_num), DynValue::R10($rhs_num)) => $res,
+            (DynValue::R11($lhs_num), DynValue::R11($rhs_num)) => $res,
+            (DynValue::R12($lhs_num), DynValue::R12($rhs_num)) => $res,
+            (DynValue::R13($lhs_num), DynValue::R13($rhs_num)) => $res,
+            (DynValue::R14($lhs_num), DynValue::R14($rhs_num)) => $res,
+            (DynValue::R15($lhs_num), DynValue::R15($rhs_num)) => $res,
+            (DynValue::R16($lhs_num), DynValue::R16($rhs_num)) => $res,
+            (DynValue::R17($lhs_num), DynValue::R17($rhs_num)) => $res,
+            (DynValue::R18($lhs_num), DynValue::R18($rhs_num)) => $res,
+            (DynValue::R19($lhs_num), DynValue::R19($rhs_num)) => $res,
+            (DynValue::R20($lhs_num), DynValue::R20($rhs_num)) => $res,
+            (DynValue::R21($lhs_num), DynValue::R21($rhs_num)) => $res,
+            (DynValue::R22($lhs_num), DynValue::R22($rhs_num)) => $res,
+            (DynValue::R23($lhs_num), DynValue::R23($rhs_num)) => $res,
+            (DynValue::R24($lhs_num), DynValue::R24($rhs_num)) => $res,
+            (DynValue::R25($lhs_num), DynValue::R25($rhs_num)) => $res,
+            (DynValue::R26($lhs_num), DynValue::R26($rhs_num)) => $res,
+            (DynValue::R27($lhs_num), DynValue::R27($rhs_num)) => $res,
+            (DynValue::R28($lhs_num), DynValue::R28($rhs_num)) => $res,
+            (DynValue::R29($lhs_num), DynValue::R29($rhs_num)) => $res,
+            (DynValue::R30($lhs_num), DynValue::R30($rhs_num)) => $res,
+            (DynValue::R31($lhs_num), DynValue::R31($rhs_num)) => $res,
+            (DynValue::R32($lhs_num), DynValue::R32($rhs_num)) => $res,
+            (DynValue::R33($lhs_num), DynValue::R33($rhs_num)) => $res,
+            (DynValue::R34($lhs_num), DynValue::R34($rhs_num)) => $res,
+            (DynValue::R35($lhs_num), DynValue::R35($rhs_num)) => $res,
+            (DynValue::R36($lhs_num), DynValue::R36($rhs_num)) => $res,
+            _ => unreachable!("operands are aligned to the same radix"),
+        }
+    };
+}
+
+/// Computes `$res` with operands aligned to the same radix and precision.
+macro_rules! binary_op {
+    ($lhs:expr, $rhs:expr, $lhs_num:ident, $rhs_num:ident => $res:expr) => {{
+        let (spec, lhs, rhs) = $lhs.align($rhs);
+        let value = spec.within(|| map_values!(lhs, rhs, $lhs_num, $rhs_num => $res));
+
+        DynNumber {
+            prec: spec.prec(),
+            value,
+        }
+    }};
+}
+
+/// Defines methods that build numbers in the current `NumberSpec`.
+macro_rules! current_fns {
+    ($($(#[$doc:meta])* $name:ident),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $name() -> Self {
+                let spec = NumberSpec::current();
+
+                Self {
+                    prec: spec.prec(),
+                    value: value_of_radix!(spec.radix(), Number::$name()),
+                }
+            }
+        )*
+    };
+}
+
+/// Defines methods that map number to number of the same radix and precision.
+macro_rules! unary_fns {
+    ($($(#[$doc:meta])* $name:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $name(self, $($arg: $ty),*) -> Self {
+                let prec = self.prec;
+                let value = self
+                    .spec()
+                    .within(|| map_value!(self.value, num => num.$name($($arg),*)));
+
+                Self { prec, value }
+            }
+        )*
+    };
+}
+
+/// Defines methods that map two numbers to number.
+macro_rules! binary_fns {
+    ($($(#[$doc:meta])* $name:ident($rhs:ident $(, $arg:ident: $ty:ty)*)),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            pub fn $name(self, $rhs: Self $(, $arg: $ty)*) -> Self {
+                binary_op!(self, $rhs, lhs, rhs => lhs.$name(rhs $(, $arg)*))
+            }
+        )*
+    };
+}
+
+/// Implements arithmetic operator and its assigning form.
+macro_rules! impl_ops {
+    ($($op:ident $name:ident $op_assign:ident $name_assign:ident),* $(,)?) => {
+        $(
+            impl $op for DynNumber {
+                type Output = Self;
+
+                fn $name(self, rhs: Self) -> Self::Output {
+                    binary_op!(self, rhs, lhs, rhs => lhs.$name(rhs))
+                }
+            }
+
+            impl $op_assign for DynNumber {
+                fn $name_assign(&mut self, rhs: Self) {
+                    *self = self.clone().$name(rhs);
+                }
+            }
+        )*
+    };
+}
+
+fn radix_of<const RADIX: u32>(_: &DynPrecNumber<RADIX>) -> u32 {
+    RADIX
+}
+
+/// Rounds number reinterpreted as number of other precision to the precision.
+fn refit<const RADIX: u32, const PREC: isize>(num: Number<RADIX, PREC>) -> Number<RADIX, PREC> {
+    match num.kind {
+        NumberKind::Finite(f) => fit_to_prec(f, PREC).overflow_to_inf(),
+        kind => Number { kind },
+    }
+}
+
+/// Number with radix and precision chosen at runtime.
+///
+/// Literals and constants take radix and precision of the current `NumberSpec`.
+/// Right operand of arithmetic is converted to radix of the left one,
+/// and result has the greater precision of operands.
+#[derive(Clone, Debug)]
+pub struct DynNumber {
+    prec: isize,
+    value: DynValue,
+}
+
+impl TryFrom<&str> for DynNumber {
+    type Error = String;
+
+    fn try_from(src: &str) -> Result<Self, Self::Error> {
+        let spec = NumberSpec::current();
+
+        let value = value_of_radix!(
+            spec.radix(),
+            Number::try_from(src).map_err(|err| err.to_string())?
+        );
+
+        Ok(Self {
+            prec: spec.prec(),
+            value,
+        })
+    }
+}
+
+impl TryFrom<String> for DynNumber {
+    type Error = String;
+
+    fn try_from(src: String) -> Result<Self, Self::Error> {
+        Self::try_from(src.as_str())
+    }
+}
+
+/// Error of conversion of `Number` to `DynNumber`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TryFromNumberError {
+    /// Radix is not in `2..=36`.
+    InadmissableRadix,
+    /// Precision is not positive.
+    InvalidPrecision,
+}
+
+impl fmt::Display for TryFromNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InadmissableRadix => write!(f, "inadmissable radix, expected 2..=36"),
+            Self::InvalidPrecision => write!(f, "invalid precision, expected positive"),
+        }
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> TryFrom<Number<RADIX, PREC>> for DynNumber {
+    type Error = TryFromNumberError;
+
+    /// Keeps radix and precision of number.
+    fn try_from(num: Number<RADIX, PREC>) -> Result<Self, Self::Error> {
+        if !(2..=36).contains(&RADIX) {
+            return Err(TryFromNumberError::InadmissableRadix);
+        }
+
+        // Marker of precision of the current spec is negative as well
+        if PREC <= 0 {
+            return Err(TryFromNumberError::InvalidPrecision);
+        }
+
+        Ok(Self {
+            prec: PREC,
+            value: value_of_radix!(RADIX, num.recast()),
+        })
+    }
+}
+
+impl fmt::Display for DynNumber {
+    /// Formats number as `Number` of its radix and precision does.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.spec()
+            .within(|| with_value!(&self.value, num => fmt::Display::fmt(num, f)))
+    }
+}
+
+impl Default for DynNumber {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl DynNumber {
+    pub fn radix(&self) -> u32 {
+        with_value!(&self.value, num => radix_of(num))
+    }
+
+    pub fn prec(&self) -> isize {
+        self.prec
+    }
+
+    pub fn spec(&self) -> NumberSpec {
+        NumberSpec::new(self.radix(), self.prec).expect("number has valid radix and precision")
+    }
+
+    /// Converts number to `Number`, rounds it to precision `PREC`.
+    pub fn to_number<const RADIX: u32, const PREC: isize>(&self) -> Number<RADIX, PREC> {
+        match self.radix() == RADIX {
+            true => with_value!(self.value.clone(), num => refit(num.recast())),
+            false => with_value!(&self.value, num => Rational::from(num.clone())).to_number(),
+        }
+    }
+
+    /// Approximates exact number in the current spec, rounds with the current rounding mode.
+    pub fn from_rational(num: &Rational) -> Self {
+        let spec = NumberSpec::current();
+
+        Self {
+            prec: spec.prec(),
+            value: value_of_radix!(spec.radix(), num.to_number()),
+        }
+    }
+
+    /// Returns operands in the same radix and their spec.
+    fn align(self, rhs: Self) -> (NumberSpec, DynValue, DynValue) {
+        let spec = NumberSpec::new(self.radix(), self.prec.max(rhs.prec))
+            .expect("numbers have valid radix and precision");
+
+        let rhs = match rhs.radix() == spec.radix() {
+            true => rhs.value,
+            false => Self::convert_value(rhs.value, spec),
+        };
+
+        (spec, self.value, rhs)
+    }
+
+    /// Converts value to radix of `spec`, rounds it to precision of `spec`.
+    fn convert_value(value: DynValue, spec: NumberSpec) -> DynValue {
+        let rational = with_value!(value, num => Rational::from(num));
+        spec.within(|| value_of_radix!(spec.radix(), rational.to_number()))
+    }
+
+    /// Converts number to `radix`, rounds fractional part to precision.
+    ///
+    /// Returns `None` if radix is not in `2..=36`.
+    pub fn to_radix(self, radix: u32) -> Option<Self> {
+        let spec = NumberSpec::new(radix, self.prec)?;
+
+        let value = match self.radix() == radix {
+            true => self.value,
+            false => Self::convert_value(self.value, spec),
+        };
+
+        Some(Self {
+            prec: self.prec,
+            value,
+        })
+    }
+
+    /// Formats number with given settings.
+    pub fn format(&self, format: &NumberFormat) -> String {
+        self.spec()
+            .within(|| with_value!(&self.value, num => num.format(format)))
+    }
+
+    pub fn is_nan(&self) -> bool {
+        with_value!(&self.value, num => num.is_nan())
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        with_value!(&self.value, num => num.is_infinite())
+    }
+
+    pub fn is_sign_negative(&self) -> bool {
+        with_value!(&self.value, num => num.is_sign_negative())
+    }
+
+    pub fn is_sign_positive(&self) -> bool {
+        with_value!(&self.value, num => num.is_sign_positive())
+    }
+
+    fn is_zero(&self) -> bool {
+        with_value!(&self.value, num => matches!(&num.kind, NumberKind::Finite(f) if f.is_zero()))
+    }
+
+    /// Compares numbers in total order of `Number::total_cmp`.
+    ///
+    /// Numbers of different radixes are compared exactly, without rounding.
+    pub fn total_cmp(&self, rhs: &Self) -> Ordering {
+        if self.radix() == rhs.radix() {
+            return with_values!(&self.value, &rhs.value, lhs, rhs => lhs.total_cmp(rhs));
+        }
+
+        match (self.is_nan(), rhs.is_nan()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return Ordering::Greater,
+            (false, true) => return Ordering::Less,
+            (false, false) => (),
+        }
+
+        let lhs_rational = with_value!(&self.value, num => Rational::from(num.clone()));
+        let rhs_rational = with_value!(&rhs.value, num => Rational::from(num.clone()));
+
+        match lhs_rational.partial_cmp(&rhs_rational) {
+            Some(Ordering::Equal) if lhs_rational.is_zero() => {
+                rhs.is_sign_negative().cmp(&self.is_sign_negative())
+            }
+            Some(ord) => ord,
+            None => unreachable!("numbers other than NaN are ordered"),
+        }
+    }
+
+    current_fns!(
+        zero,
+        one,
+        inf,
+        neg_inf,
+        nan,
+        /// Returns `π`.
+        pi,
+        /// Returns `τ = 2π`.
+        tau,
+        /// Returns Euler's number `e`.
+        e,
+        /// Returns golden ratio `φ`.
+        phi,
+    );
+
+    unary_fns!(
+        abs(),
+        /// Returns integer part of number.
+        trunc(),
+        /// Returns the largest integer less than or equal to number.
+        floor(),
+        /// Returns the smallest integer greater than or equal to number.
+        ceil(),
+        /// Returns the nearest integer to number, rounds half away from zero.
+        round(),
+        /// Rounds number to `digits` fractional digits with given rounding mode.
+        round_to(digits: isize, mode: RoundingMode),
+        /// Computes `e^self`.
+        exp(),
+        /// Computes natural logarithm.
+        ln(),
+        /// Computes base-10 logarithm.
+        log10(),
+        /// Computes square root.
+        sqrt(),
+        /// Computes hyperbolic sine.
+        sinh(),
+        /// Computes hyperbolic cosine.
+        cosh(),
+        /// Computes hyperbolic tangent.
+        tanh(),
+        /// Computes inverse hyperbolic sine.
+        asinh(),
+        /// Computes inverse hyperbolic cosine.
+        acosh(),
+        /// Computes inverse hyperbolic tangent.
+        atanh(),
+        /// Converts angle in degrees to radians.
+        to_radians(),
+        /// Converts angle in radians to degrees.
+        to_degrees(),
+        /// Computes sine of angle in radians.
+        sin(),
+        /// Computes sine of angle in given unit.
+        sin_in(unit: AngleUnit),
+        /// Computes cosine of angle in radians.
+        cos(),
+        /// Computes cosine of angle in given unit.
+        cos_in(unit: AngleUnit),
+        /// Computes tangent of angle in radians.
+        tan(),
+        /// Computes tangent of angle in given unit.
+        tan_in(unit: AngleUnit),
+        /// Computes arcsine in radians.
+        asin(),
+        /// Computes arcsine in given unit.
+        asin_in(unit: AngleUnit),
+        /// Computes arccosine in radians.
+        acos(),
+        /// Computes arccosine in given unit.
+        acos_in(unit: AngleUnit),
+        /// Computes arctangent in radians.
+        atan(),
+        /// Computes arctangent in given unit.
+        atan_in(unit: AngleUnit),
+    );
+
+    binary_fns!(
+        /// Raises number to power.
+        pow(exp),
+        /// Computes logarithm to `base`.
+        log(base),
+        /// Computes `n`-th root.
+        nth_root(n),
+        /// Computes angle of point `(x, self)` in radians.
+        atan2(x),
+        /// Computes angle of point `(x, self)` in given unit.
+        atan2_in(x, unit: AngleUnit),
+        /// Divides number by `rhs` and rounds quotient toward negative infinity.
+        div_floor(rhs),
+        /// Computes remainder of floor division, it has the sign of divisor.
+        modulo(rhs),
+        /// Computes non-negative remainder of division.
+        rem_euclid(rhs),
+    );
+
+    /// Divides number by `rhs`, returns quotient truncated to integer
+    /// and remainder that has the sign of dividend.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        let quotient = binary_op!(self.clone(), rhs.clone(), lhs, rhs => lhs.div_rem(rhs).0);
+        let rem = binary_op!(self, rhs, lhs, rhs => lhs % rhs);
+
+        (quotient, rem)
+    }
+
+    /// Adds numbers, fails instead of returning infinity or NaN for finite or infinite operands.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        ArithmeticError::check(|| self + rhs)
+    }
+
+    /// Subtracts numbers, fails as `checked_add` does.
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        ArithmeticError::check(|| self - rhs)
+    }
+
+    /// Multiplies numbers, fails as `checked_add` does.
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        ArithmeticError::check(|| self * rhs)
+    }
+
+    /// Divides numbers, fails as `checked_add` does and on division by zero.
+    pub fn checked_div(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        ArithmeticError::check(|| self / rhs)
+    }
+}
+
+impl PartialEq for DynNumber {
+    /// Numbers are compared by value regardless of radix and precision,
+    /// zeros are equal regardless of sign, NaN is not equal to any number including itself.
+    fn eq(&self, rhs: &Self) -> bool {
+        matches!(self.partial_cmp(rhs), Some(Ordering::Equal))
+    }
+}
+
+impl PartialOrd for DynNumber {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        if self.is_nan() || rhs.is_nan() {
+            return None;
+        }
+
+        match self.is_zero() && rhs.is_zero() {
+            true => Some(Ordering::Equal),
+            false => Some(self.total_cmp(rhs)),
+        }
+    }
+}
+
+/// Numbers of different radixes are not equal in total order, even if their values are,
+/// so equal numbers have equal canonical digits, exponent and radix.
+impl PartialEq for TotalOrder<DynNumber> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.cmp(rhs) == Ordering::Equal
+    }
+}
+
+impl Eq for TotalOrder<DynNumber> {}
+
+/// Hashes radix and canonical value of number, regardless of precision
+/// and trailing zeros of significand.
+impl Hash for TotalOrder<DynNumber> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.radix().hash(state);
+        with_value!(&self.0.value, num => num.hash(state))
+    }
+}
+
+impl PartialOrd for TotalOrder<DynNumber> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+/// Numbers are ordered by `total_cmp`, equal values of different radixes by radix.
+impl Ord for TotalOrder<DynNumber> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.0
+            .total_cmp(&rhs.0)
+            .then_with(|| self.0.radix().cmp(&rhs.0.radix()))
+    }
+}
+
+impl Neg for DynNumber {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let prec = self.prec;
+        let value = map_value!(self.value, num => -num);
+
+        Self { prec, value }
+    }
+}
+
+impl_ops!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign,
+    Rem rem RemAssign rem_assign,
+);
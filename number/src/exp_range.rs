@@ -0,0 +1,63 @@
+use std::cell::Cell;
+
+/// Range of positions of the most significant digit of finite numbers.
+///
+/// Numbers whose most significant digit is above `emax` overflow to infinity.
+/// Numbers below `emin` lose precision gradually, digits below `etiny` are rounded off.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ExpRange {
+    emin: isize,
+    emax: isize,
+}
+
+impl Default for ExpRange {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+thread_local! {
+    static CURRENT: Cell<ExpRange> = Cell::new(ExpRange::default());
+}
+
+impl ExpRange {
+    pub const DEFAULT: Self = Self {
+        emin: -999_999,
+        emax: 999_999,
+    };
+
+    /// Creates range of exponents.
+    ///
+    /// Returns `None` if the range does not contain zero.
+    pub fn new(emin: isize, emax: isize) -> Option<Self> {
+        match emin <= 0 && emax >= 0 {
+            true => Some(Self { emin, emax }),
+            false => None,
+        }
+    }
+
+    pub fn emin(&self) -> isize {
+        self.emin
+    }
+
+    pub fn emax(&self) -> isize {
+        self.emax
+    }
+
+    /// Returns position of the least significant digit of the smallest subnormal number.
+    pub fn etiny(&self, prec: isize) -> isize {
+        self.emin.saturating_sub(prec - 1)
+    }
+
+    /// Returns exponent range of arithmetic in the current thread.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.get())
+    }
+
+    /// Sets exponent range of arithmetic in the current thread.
+    ///
+    /// Returns the previous range.
+    pub fn set_current(self) -> Self {
+        CURRENT.with(|current| current.replace(self))
+    }
+}
@@ -1,27 +1,41 @@
+use crate::exp_range::ExpRange;
+use crate::limbs::{
+    limbs_add, limbs_digit, limbs_digits_len, limbs_div_rem, limbs_from_digits, limbs_from_u128,
+    limbs_from_u64, limbs_is_odd, limbs_mul, limbs_mul_small, limbs_pow, limbs_shl_digits,
+    limbs_split_digits, limbs_trailing_zeros,
+};
+use crate::number_spec::prec_of;
 use crate::sign::Sign;
 use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::ops::Range;
 
-const MAX_PREC: isize = 100;
-
 pub struct FiniteOverflow {
     pub sign: Sign,
 }
 
+pub type FiniteResult<const RADIX: u32, const PREC: isize> =
+    Result<Finite<RADIX, PREC>, FiniteOverflow>;
+
 #[derive(Debug, Clone)]
 pub enum TryFromStrError<const RADIX: u32, const PREC: isize> {
     Empty,
-    TooLong,
     InadmissableRadix,
     InvalidDigit,
     InvalidPrecision,
     SeveralPoints,
     PointWithoutFracPart,
+    InvalidExponent,
+    InvalidLiteralRadix,
+    ExponentOutOfRange,
 }
 
 #[derive(Clone, Debug)]
 pub struct Finite<const RADIX: u32, const PREC: isize> {
+    /// Significand packed into limbs of several digits, the least significant limb first.
+    /// Zero has no limbs.
     mantissa: Vec<u32>,
+    /// Position of the least significant digit of significand.
     exp: isize,
     sign: Sign,
 }
@@ -29,77 +43,216 @@ pub struct Finite<const RADIX: u32, const PREC: isize> {
 impl<const RADIX: u32, const PREC: isize> ToString for TryFromStrError<RADIX, PREC> {
     fn to_string(&self) -> String {
         match self {
-            Self::Empty => format!("Empty"),
-            Self::TooLong => format!("too long"),
+            Self::Empty => "Empty".to_string(),
             Self::InadmissableRadix => format!("inadmissable radix, used radix - {}", RADIX),
-            Self::InvalidDigit => format!("invalid digit, maybe invalid digit radix"),
+            Self::InvalidDigit => "invalid digit, maybe invalid digit radix".to_string(),
             Self::InvalidPrecision => {
-                format!("invalid precision, max precision - {}", PREC)
+                format!("invalid precision, used precision - {}", prec_of::<PREC>())
             }
-            Self::SeveralPoints => format!("several points"),
-            Self::PointWithoutFracPart => format!("point without frac part"),
+            Self::SeveralPoints => "several points".to_string(),
+            Self::PointWithoutFracPart => "point without frac part".to_string(),
+            Self::InvalidExponent => "invalid exponent".to_string(),
+            Self::InvalidLiteralRadix => "invalid literal radix, expected 2..=36".to_string(),
+            Self::ExponentOutOfRange => "exponent out of range".to_string(),
         }
-        .to_string()
-    }
-}
-
-impl FiniteOverflow {
-    pub fn set_sign(mut self, sign: &Sign) -> Self {
-        self.sign = sign.clone();
-        self
     }
 }
 
 impl<const RADIX: u32, const PREC: isize> Finite<RADIX, PREC> {
-    fn raw_part_to_mantissa(raw_part: &str) -> Result<Vec<u32>, TryFromStrError<RADIX, PREC>> {
-        let is_valid = raw_part
-            .chars()
-            .fold(true, |acc, sym| acc && sym.is_digit(RADIX));
+    fn raw_part_to_digits(
+        raw_part: &str,
+        radix: u32,
+    ) -> Result<Vec<u32>, TryFromStrError<RADIX, PREC>> {
+        let is_valid = raw_part.chars().all(|sym| sym.is_digit(radix));
 
         if !is_valid {
             return Err(TryFromStrError::<RADIX, PREC>::InvalidDigit);
         }
 
-        let mantissa = raw_part
+        let digits = raw_part
             .chars()
-            .map(|sym| sym.to_digit(RADIX).unwrap())
+            .map(|sym| sym.to_digit(radix).unwrap())
             .collect();
 
-        Ok(mantissa)
+        Ok(digits)
     }
 
+    /// Parses decimal exponent with optional sign.
+    fn raw_part_to_exp(raw_part: &str) -> Result<isize, TryFromStrError<RADIX, PREC>> {
+        let digits = raw_part.trim_start_matches(['+', '-']);
+
+        if digits.is_empty() || raw_part.len() - digits.len() > 1 {
+            return Err(TryFromStrError::<RADIX, PREC>::InvalidExponent);
+        }
+
+        if !digits.chars().all(|sym| sym.is_ascii_digit()) {
+            return Err(TryFromStrError::<RADIX, PREC>::InvalidExponent);
+        }
+
+        raw_part
+            .parse::<isize>()
+            .map_err(|_| TryFromStrError::<RADIX, PREC>::InvalidExponent)
+    }
+
+    /// Splits radix prefix `0x`, `0o`, `0b` or `radix#` off literal.
+    ///
+    /// Literal without prefix has radix `RADIX`. Letters of prefixes that are digits
+    /// of `RADIX` start no prefix, e.g. `0b1` is hexadecimal if `RADIX` is 16.
+    fn split_radix_prefix(src: &str) -> Result<(u32, &str), TryFromStrError<RADIX, PREC>> {
+        let prefixed = [
+            ("0x", 16),
+            ("0X", 16),
+            ("0o", 8),
+            ("0O", 8),
+            ("0b", 2),
+            ("0B", 2),
+        ]
+        .into_iter()
+        .filter(|(prefix, _)| !prefix.ends_with(|chr: char| chr.is_digit(RADIX)))
+        .find_map(|(prefix, radix)| src.strip_prefix(prefix).map(|src| (radix, src)));
+
+        let (radix, src) = match (prefixed, src.split_once('#')) {
+            (Some(prefixed), _) => prefixed,
+            (None, Some((raw_radix, src))) => match raw_radix.parse::<u32>() {
+                Ok(radix) if (2..=36).contains(&radix) => (radix, src),
+                _ => return Err(TryFromStrError::<RADIX, PREC>::InvalidLiteralRadix),
+            },
+            (None, None) => return Ok((RADIX, src)),
+        };
+
+        match src.is_empty() {
+            true => Err(TryFromStrError::<RADIX, PREC>::Empty),
+            false => Ok((radix, src)),
+        }
+    }
+
+    /// Builds number from integer, fractional and exponent parts written in `radix`.
+    ///
+    /// Exponent is decimal, the number is multiplied by `radix` raised to it.
     fn from_raw_parts<'s>(
         raw_int_part: Option<&'s str>,
         raw_frac_part: Option<&'s str>,
+        raw_exp_part: Option<&'s str>,
+        radix: u32,
     ) -> Result<Self, TryFromStrError<RADIX, PREC>> {
+        let exp = match raw_exp_part {
+            Some(raw_exp_part) => Self::raw_part_to_exp(raw_exp_part)?,
+            None => 0,
+        };
+
+        let (digits, frac_len) =
+            Self::raw_mantissa_parts_to_digits(raw_int_part, raw_frac_part, radix)?;
+
+        match radix == RADIX {
+            true => Ok(Self::from_digits(&digits, exp - frac_len)),
+            false => {
+                let mantissa = digits.into_iter().fold(Vec::new(), |acc, digit| {
+                    let acc = limbs_mul_small::<RADIX>(&acc, radix);
+                    limbs_add::<RADIX>(&acc, &limbs_from_u64::<RADIX>(digit as u64))
+                });
+
+                Ok(Self::from_scaled_limbs(mantissa, radix, exp - frac_len))
+            }
+        }
+    }
+
+    /// Returns digits of significand and count of fractional digits.
+    fn raw_mantissa_parts_to_digits<'s>(
+        raw_int_part: Option<&'s str>,
+        raw_frac_part: Option<&'s str>,
+        radix: u32,
+    ) -> Result<(Vec<u32>, isize), TryFromStrError<RADIX, PREC>> {
         let int_part = if let Some(raw_int_part) = raw_int_part {
-            Self::raw_part_to_mantissa(raw_int_part)?
+            Self::raw_part_to_digits(raw_int_part, radix)?
         } else {
             return Err(TryFromStrError::<RADIX, PREC>::Empty);
         };
 
         let frac_part = if let Some(raw_frac_part) = raw_frac_part {
-            Self::raw_part_to_mantissa(raw_frac_part)?
+            Self::raw_part_to_digits(raw_frac_part, radix)?
         } else {
-            return Ok(Self {
-                exp: 0,
-                mantissa: int_part,
-                sign: Sign::Pos,
-            });
+            return Ok((int_part, 0));
         };
 
         if frac_part.len() == 0 {
             return Err(TryFromStrError::<RADIX, PREC>::PointWithoutFracPart);
         }
 
-        let exp = -(frac_part.len() as isize);
-        let mantissa = [int_part, frac_part].concat();
+        let frac_len = frac_part.len() as isize;
 
-        Ok(Self {
-            exp,
-            mantissa,
-            sign: Sign::Pos,
-        })
+        Ok(([int_part, frac_part].concat(), frac_len))
+    }
+
+    /// Builds positive number `mantissa * base^exp`, where `mantissa` is in limbs of `RADIX`.
+    ///
+    /// Negative power of `base` may have no finite representation in `RADIX`,
+    /// then the number keeps more than `PREC` digits of quotient and a nonzero digit
+    /// after them, so that rounding it to precision rounds the exact value.
+    ///
+    /// Numbers far out of the current exponent range are not scaled exactly,
+    /// they are replaced with a number that rounds to infinity or zero the same way.
+    pub fn from_scaled_limbs(mantissa: Vec<u32>, base: u32, exp: isize) -> Self {
+        if mantissa.is_empty() {
+            return Self::from_limbs(mantissa, 0);
+        }
+
+        // The most significant digit is at `msd_pos` or one position above
+        let msd_pos = (limbs_digits_len::<RADIX>(&mantissa) - 1) as f64
+            + exp as f64 * (base as f64).ln() / (RADIX as f64).ln();
+        let range = ExpRange::current();
+
+        if msd_pos > range.emax().saturating_add(2) as f64 {
+            return Self::from_limbs(vec![1], range.emax().saturating_add(1));
+        }
+
+        let etiny = range.etiny(prec_of::<PREC>());
+
+        if msd_pos < etiny.saturating_sub(3) as f64 {
+            return Self::from_limbs(vec![1], etiny.saturating_sub(2));
+        }
+
+        let scale = limbs_pow::<RADIX>(
+            &limbs_from_u64::<RADIX>(base as u64),
+            exp.unsigned_abs() as u64,
+        );
+
+        if exp >= 0 {
+            return Self::from_limbs(limbs_mul::<RADIX>(&mantissa, &scale), 0);
+        }
+
+        let shift = (prec_of::<PREC>() + 2 + limbs_digits_len::<RADIX>(&scale) as isize
+            - limbs_digits_len::<RADIX>(&mantissa) as isize)
+            .max(0);
+        let mantissa = limbs_shl_digits::<RADIX>(&mantissa, shift as usize);
+        let (quotient, rem) = limbs_div_rem::<RADIX>(&mantissa, &scale);
+
+        match rem.is_empty() {
+            true => Self::from_limbs(quotient, -shift),
+            false => {
+                let sticky = limbs_shl_digits::<RADIX>(&quotient, 1);
+                Self::from_limbs(limbs_add::<RADIX>(&sticky, &[1]), -shift - 1)
+            }
+        }
+    }
+}
+
+/// Returns exponent marker of numbers in `radix`.
+///
+/// Marker `e` is a digit in radixes greater than 14, then `@` is used instead.
+pub fn exp_marker_of(radix: u32) -> char {
+    match 'e'.is_digit(radix) {
+        true => '@',
+        false => 'e',
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> Finite<RADIX, PREC> {
+    /// Checks if symbol starts exponent part of literal in `radix`.
+    ///
+    /// Exponent markers `e` and `E` are digits in radixes greater than 14,
+    /// so such numbers have exponent part only after `@`, which is a marker in any radix.
+    fn is_exp_marker(sym: char, radix: u32) -> bool {
+        sym == '@' || (matches!(sym, 'e' | 'E') && !sym.is_digit(radix))
     }
 }
 
@@ -111,14 +264,10 @@ impl<const RADIX: u32, const PREC: isize> TryFrom<&str> for Finite<RADIX, PREC>
             return Err(TryFromStrError::<RADIX, PREC>::InadmissableRadix);
         }
 
-        if PREC <= 0 || PREC > MAX_PREC {
+        if prec_of::<PREC>() <= 0 {
             return Err(TryFromStrError::<RADIX, PREC>::InvalidPrecision);
         }
 
-        if isize::try_from(src.len()).map_err(|_| TryFromStrError::TooLong)? > MAX_PREC {
-            return Err(TryFromStrError::<RADIX, PREC>::TooLong);
-        }
-
         let mut chars = src.chars().peekable();
 
         let sign = match chars.peek() {
@@ -134,6 +283,12 @@ impl<const RADIX: u32, const PREC: isize> TryFrom<&str> for Finite<RADIX, PREC>
         };
 
         let src = chars.collect::<String>();
+        let (radix, src) = Self::split_radix_prefix(&src)?;
+
+        let (src, exp_part) = match src.find(|sym| Self::is_exp_marker(sym, radix)) {
+            Some(marker_pos) => (&src[..marker_pos], Some(&src[marker_pos + 1..])),
+            None => (src, None),
+        };
 
         let mut parts = src.split(|sym| sym == '.');
         let (int_part, frac_part) = (parts.next(), parts.next());
@@ -142,14 +297,52 @@ impl<const RADIX: u32, const PREC: isize> TryFrom<&str> for Finite<RADIX, PREC>
             return Err(TryFromStrError::<RADIX, PREC>::SeveralPoints);
         }
 
-        let num = Self::from_raw_parts(int_part, frac_part)?;
+        let num = Self::from_raw_parts(int_part, frac_part, exp_part, radix)?;
 
         Ok(num.trim_zeros().set_sign(&sign))
     }
 }
 
+impl<const RADIX: u32, const PREC: isize> From<u64> for Finite<RADIX, PREC> {
+    fn from(int: u64) -> Self {
+        Self::from_limbs(limbs_from_u64::<RADIX>(int), 0)
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> Finite<RADIX, PREC> {
+    /// Formats number as significant digits followed by decimal exponent,
+    /// e.g. `1.5e-70`, or `f.8@-70` in radixes whose digits include `e`.
+    fn to_exp_string(&self) -> String {
+        let mut digits = self
+            .digits()
+            .into_iter()
+            .map(|digit| char::from_digit(digit, RADIX).unwrap());
+
+        let mut val = String::new();
+
+        if self.is_neg() {
+            val.push('-');
+        }
+
+        val.extend(digits.next());
+
+        if self.digits_len() > 1 {
+            val.push('.');
+            val.extend(digits);
+        }
+
+        format!("{}{}{}", val, exp_marker_of(RADIX), self.msd_pos())
+    }
+}
+
 impl<const RADIX: u32, const PREC: isize> ToString for Finite<RADIX, PREC> {
+    /// Formats number positionally if its integer part fits precision
+    /// and it has less than six leading fractional zeros, otherwise with exponent.
     fn to_string(&self) -> String {
+        if !self.is_zero() && (self.msd_pos() >= prec_of::<PREC>() || self.msd_pos() < -6) {
+            return self.to_exp_string();
+        }
+
         let mut val = Vec::new();
 
         for pos in self.bounds() {
@@ -172,41 +365,109 @@ impl<const RADIX: u32, const PREC: isize> ToString for Finite<RADIX, PREC> {
     }
 }
 
-impl<const RADIX: u32, const PREC: isize> Finite<RADIX, PREC> {
-    fn trim_left_zeros(mut self) -> Self {
-        self.mantissa.reverse();
+/// Hashes value of number, so numbers equal by `finite_cmp` have equal hashes
+/// regardless of trailing zeros of significand and sign of zero.
+impl<const RADIX: u32, const PREC: isize> Hash for Finite<RADIX, PREC> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if self.is_zero() {
+            return 0u8.hash(state);
+        }
 
-        while let Some(digit) = self.mantissa.pop() {
-            if digit == 0 && self.mantissa.len() != 0 {
-                continue;
-            }
+        let mut digits = self.digits();
+
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+
+        self.is_neg().hash(state);
+        self.msd_pos().hash(state);
+        digits.hash(state);
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> Finite<RADIX, PREC> {
+    /// Drops zero digits at both ends of significand.
+    pub fn trim_zeros(mut self) -> Self {
+        while self.mantissa.last() == Some(&0) {
+            self.mantissa.pop();
+        }
 
-            self.mantissa.push(digit);
-            break;
+        if self.is_zero() {
+            self.exp = 0;
+            return self;
         }
 
-        self.mantissa.reverse();
+        let zeros_len = limbs_trailing_zeros::<RADIX>(&self.mantissa);
+
+        if zeros_len != 0 {
+            self.mantissa = limbs_split_digits::<RADIX>(&self.mantissa, zeros_len).0;
+            self.exp += zeros_len as isize;
+        }
 
         self
     }
 
-    fn trim_right_zeros(mut self) -> Self {
-        while let Some(digit) = self.mantissa.pop() {
-            if digit == 0 && self.mantissa.len() != 0 {
-                self.exp += 1;
+    /// Builds positive number from digits, the most significant first,
+    /// and position of the least significant digit.
+    pub fn from_digits(digits: &[u32], exp: isize) -> Self {
+        Self::from_limbs(limbs_from_digits::<RADIX>(digits), exp)
+    }
 
-                continue;
-            }
+    /// Builds positive integer, unlike `From<u64>` it takes integers of any width.
+    pub fn from_u128(int: u128) -> Self {
+        Self::from_limbs(limbs_from_u128::<RADIX>(int), 0)
+    }
 
-            self.mantissa.push(digit);
-            break;
+    /// Builds positive number from limbs of significand
+    /// and position of its least significant digit.
+    pub fn from_limbs(limbs: Vec<u32>, exp: isize) -> Self {
+        Self {
+            mantissa: limbs,
+            exp,
+            sign: Sign::Pos,
         }
+        .trim_zeros()
+    }
 
-        self
+    /// Reinterprets number as number of other precision, radixes must be equal.
+    pub fn recast<const TO_RADIX: u32, const TO_PREC: isize>(self) -> Finite<TO_RADIX, TO_PREC> {
+        debug_assert_eq!(RADIX, TO_RADIX, "recast between different radixes");
+
+        Finite {
+            mantissa: self.mantissa,
+            exp: self.exp,
+            sign: self.sign,
+        }
     }
 
-    pub fn trim_zeros(self) -> Self {
-        self.trim_left_zeros().trim_right_zeros()
+    /// Returns limbs of significand.
+    pub fn limbs(&self) -> &[u32] {
+        &self.mantissa
+    }
+
+    /// Returns limbs of significands of numbers multiplied by powers of radix,
+    /// so that their least significant digits are at the same position, and the position.
+    pub fn aligned_limbs(&self, other: &Self) -> (Vec<u32>, Vec<u32>, isize) {
+        let exp = self.exp.min(other.exp);
+
+        (
+            limbs_shl_digits::<RADIX>(&self.mantissa, (self.exp - exp).unsigned_abs()),
+            limbs_shl_digits::<RADIX>(&other.mantissa, (other.exp - exp).unsigned_abs()),
+            exp,
+        )
+    }
+
+    /// Returns count of significant digits, zero has one digit.
+    fn digits_len(&self) -> isize {
+        limbs_digits_len::<RADIX>(&self.mantissa).max(1) as isize
+    }
+
+    /// Returns digits of significand, the most significant first.
+    fn digits(&self) -> Vec<u32> {
+        (0..self.digits_len() as usize)
+            .rev()
+            .map(|pos| limbs_digit::<RADIX>(&self.mantissa, pos))
+            .collect()
     }
 
     pub fn set_sign(mut self, sign: &Sign) -> Self {
@@ -243,13 +504,31 @@ impl<const RADIX: u32, const PREC: isize> Finite<RADIX, PREC> {
     pub fn zero() -> Self {
         Self {
             exp: 0,
-            mantissa: vec![0],
+            mantissa: Vec::new(),
             sign: Sign::Pos,
         }
     }
 
     pub fn is_zero(&self) -> bool {
-        self.mantissa.len() == 1 && matches!(self.mantissa.first(), Some(&0))
+        self.mantissa.iter().all(|limb| *limb == 0)
+    }
+
+    pub fn is_int(&self) -> bool {
+        self.exp >= 0
+            || limbs_split_digits::<RADIX>(&self.mantissa, self.exp.unsigned_abs())
+                .1
+                .is_empty()
+    }
+
+    /// Checks if integer part of number is odd.
+    pub fn is_odd(&self) -> bool {
+        match self.exp {
+            exp if exp > 0 && RADIX.is_multiple_of(2) => false,
+            exp if exp >= 0 => limbs_is_odd::<RADIX>(&self.mantissa),
+            exp => limbs_is_odd::<RADIX>(
+                &limbs_split_digits::<RADIX>(&self.mantissa, exp.unsigned_abs()).0,
+            ),
+        }
     }
 
     pub fn one() -> Self {
@@ -261,105 +540,38 @@ impl<const RADIX: u32, const PREC: isize> Finite<RADIX, PREC> {
     }
 
     pub fn is_one(&self) -> bool {
-        self.mantissa.len() == 1 && matches!(self.mantissa.first(), Some(&1))
+        self.mantissa.len() == 1 && matches!(self.mantissa.first(), Some(&1)) && self.exp == 0
     }
 
-    /// Converts position.
-    ///
-    /// *mantissa element pos* **(=>)** *digit pos*
-    /// **or**
-    /// *digit pos* **(=>)** *mantissa element pos*
+    /// Converts position of digit in number to position in significand,
+    /// counted from its least significant digit.
     ///
     /// # Explanations
     ///
     /// **exponent: -4**
     ///
-    /// | pos         | 0     | 1     | 2     | 3     | 4     | 5     | 6     | 7     | 8     | 9     | 10    | 11    |
-    /// |-------------| :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: |
-    /// | mantissa | 3     | 3     | 1     | 6     | 5     | 4     | 8     | 7     | 5     | 0     | 2     | 1     |
+    /// | pos         | 11    | 10    | 9     | 8     | 7     | 6     | 5     | 4     |       | 3     | 2     | 1     | 0     |
+    /// |-------------| :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: |
+    /// | significand | 3     | 3     | 1     | 6     | 5     | 4     | 8     | 7     |       | 5     | 0     | 2     | 1     |
     ///
     /// | pos    | 7     | 6     | 5     | 4     | 3     | 2     | 1     | 0     |       | -1    | -2    | -3    | -4    |
     /// |--------| :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: |
     /// | number | 3     | 3     | 1     | 6     | 5     | 4     | 8     | 7     | .     | 5     | 0     | 2     | 1     |
     ///
-    /// **exponent: 5**
-    ///
-    /// | pos         | 0     | 1     | 2     | 3     | 4     | 5     | 6     | 7     | 8     |
-    /// |-------------| :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: |
-    /// | mantissa | 6     | 0     | 3     | 3     | 5     | 2     | 7     | 4     | 9     |
-    ///
-    /// | pos    | 13    | 12    | 11    | 10    | 9     | 8     | 7     | 6     | 5     | 4     | 3     | 2     | 1     | 0     |
-    /// |--------| :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: |
-    /// | number | 6     | 0     | 3     | 3     | 5     | 2     | 7     | 4     | 9     | 0     | 0     | 0     | 0     | 0     |
-    ///
-    /// **exponent: -8**
-    ///
-    /// | pos         | 0     | 1     | 2     | 3     |
-    /// |-------------| :---: | :---: | :---: | :---: |
-    /// | mantissa | 3     | 3     | 2     | 9     |
-    ///
-    /// | pos    | 0     |       | -1    | -2    | -3    | -4    | -5    | -6    | -7    | -8    |
-    /// |--------| :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: | :---: |
-    /// | number | 3     | .     | 0     | 0     | 0     | 0     | 3     | 3     | 2     | 9     |
-    ///
     fn convert_pos(&self, pos: isize) -> isize {
-        (self.mantissa.len() as isize) + self.exp - pos - 1
-    }
-
-    fn set_mantissa_element(&mut self, val: u32, pos: usize) {
-        for _ in self.mantissa.len()..=pos {
-            self.mantissa.push(0)
-        }
-
-        match self.mantissa.get_mut(pos) {
-            Some(current_item) => {
-                *current_item = val;
-            }
-            _ => (),
-        }
-    }
-
-    pub fn set_digit(&mut self, digit: u32, pos: isize) {
-        debug_assert!(
-            digit < RADIX,
-            "radix of digit is not equal to default radix"
-        );
-
-        if pos < self.exp {
-            self.exp = pos;
-        }
-
-        let pos = self.convert_pos(pos);
-
-        if pos >= 0 {
-            self.set_mantissa_element(digit, pos.unsigned_abs());
-            return;
-        }
-
-        let pos = pos.unsigned_abs();
-
-        self.mantissa.reverse();
-        self.set_mantissa_element(digit, self.mantissa.len() - 1 + pos);
-        self.mantissa.reverse();
+        pos - self.exp
     }
 
     pub fn get_digit(&self, pos: isize) -> Option<u32> {
         let pos = self.convert_pos(pos);
 
-        if pos >= 0 {
-            self.mantissa.get(pos.unsigned_abs()).map(|d| *d)
+        if pos >= 0 && pos < self.digits_len() {
+            Some(limbs_digit::<RADIX>(&self.mantissa, pos.unsigned_abs()))
         } else {
             None
         }
     }
 
-    pub fn merge_bounds(&self, other: &Self) -> Range<isize> {
-        let merged_start_bound = self.start_bound().min(other.start_bound());
-        let merged_end_bound = self.end_bound().max(other.end_bound());
-
-        merged_start_bound..merged_end_bound
-    }
-
     pub fn bounds(&self) -> Range<isize> {
         self.start_bound()..self.end_bound()
     }
@@ -373,7 +585,7 @@ impl<const RADIX: u32, const PREC: isize> Finite<RADIX, PREC> {
     }
 
     pub fn end_bound(&self) -> isize {
-        let end_bound = self.convert_pos(0) + 1;
+        let end_bound = self.msd_pos() + 1;
 
         if end_bound <= 0 {
             1
@@ -386,12 +598,108 @@ impl<const RADIX: u32, const PREC: isize> Finite<RADIX, PREC> {
         self.end_bound()
     }
 
-    pub fn frac_len(&self) -> isize {
-        -self.start_bound()
+    /// Returns position of the most significant digit.
+    pub fn msd_pos(&self) -> isize {
+        self.exp + self.digits_len() - 1
+    }
+
+    /// Returns position of the least significant digit.
+    pub fn lsd_pos(&self) -> isize {
+        self.exp
     }
 
-    pub fn len(&self) -> isize {
-        self.end_bound() - self.start_bound()
+    /// Truncates fractional part.
+    ///
+    /// Drops all digits whose position is less than `-frac_len`.
+    pub fn truncate_frac(mut self, frac_len: isize) -> Self {
+        let cut_len = -frac_len - self.exp;
+
+        if cut_len <= 0 {
+            return self;
+        }
+
+        if cut_len >= self.digits_len() {
+            return Self::zero().set_sign_of(&self);
+        }
+
+        self.mantissa = limbs_split_digits::<RADIX>(&self.mantissa, cut_len.unsigned_abs()).0;
+        self.exp = -frac_len;
+
+        self.trim_zeros()
+    }
+
+    /// Count of radix digits that `f64` holds without loss.
+    fn f64_digits() -> isize {
+        (f64::MANTISSA_DIGITS as f64 / (RADIX as f64).log2()).floor() as isize
+    }
+
+    /// Splits number into `f64` approximation of the most significant digits
+    /// and power of radix they are multiplied by.
+    fn approx_parts(&self) -> (f64, isize) {
+        let digits_len = self.digits_len().min(Self::f64_digits());
+        let msd_pos = self.msd_pos();
+
+        let val = (0..digits_len).fold(0f64, |acc, pos| {
+            acc * RADIX as f64 + self.get_digit(msd_pos - pos).unwrap_or(0) as f64
+        });
+
+        let val = match self.sign {
+            Sign::Neg => -val,
+            Sign::Pos => val,
+        };
+
+        (val, self.msd_pos() - digits_len + 1)
+    }
+
+    /// Approximates number by `f64`.
+    ///
+    /// Only the most significant digits are taken into account.
+    pub fn approx(&self) -> f64 {
+        let (val, exp) = self.approx_parts();
+        val * (RADIX as f64).powi(exp as i32)
+    }
+
+    /// Approximates natural logarithm of number modulus by `f64`.
+    ///
+    /// Unlike `approx`, does not overflow for numbers out of `f64` range.
+    pub fn approx_ln(&self) -> f64 {
+        let (val, exp) = self.approx_parts();
+        val.abs().ln() + exp as f64 * (RADIX as f64).ln()
+    }
+
+    /// Converts integer part of number to `u64`.
+    ///
+    /// Returns `None` if it does not fit.
+    pub fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|int| u64::try_from(int).ok())
+    }
+
+    /// Converts integer part of number modulus to `u128`.
+    ///
+    /// Returns `None` if it does not fit.
+    pub fn to_u128(&self) -> Option<u128> {
+        (0..self.end_bound()).rev().try_fold(0u128, |acc, pos| {
+            acc.checked_mul(RADIX as u128)?
+                .checked_add(self.get_digit(pos).unwrap_or(0) as u128)
+        })
+    }
+
+    /// Builds number from `f64` approximation.
+    ///
+    /// Returns zero if `val` is not finite.
+    pub fn from_approx(val: f64) -> Self {
+        if !val.is_finite() || val == 0f64 {
+            return Self::zero();
+        }
+
+        let radix = RADIX as f64;
+        let msd_pos = val.abs().log(radix).floor() as isize;
+        let shift = Self::f64_digits() - 1 - msd_pos;
+        let int = (val.abs() * radix.powi(shift as i32)).round() as u64;
+
+        let sign = if val < 0f64 { Sign::Neg } else { Sign::Pos };
+
+        Self::from(int).shift_point(-shift).set_sign(&sign)
     }
 
     /// Shifts poitnt.
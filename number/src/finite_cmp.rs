@@ -1,26 +1,25 @@
 use crate::finite::Finite;
+use crate::limbs::limbs_cmp;
 use std::cmp::Ordering;
 
 pub fn unsigned_finite_cmp<const RADIX: u32, const PREC: isize>(
     lhs: &Finite<RADIX, PREC>,
     rhs: &Finite<RADIX, PREC>,
 ) -> Ordering {
-    match lhs.int_len().cmp(&rhs.int_len()) {
-        Ordering::Equal => (),
-        ord => return ord,
+    match (lhs.is_zero(), rhs.is_zero()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        (false, false) => (),
     }
 
-    for pos in lhs.merge_bounds(&rhs).rev() {
-        let lhs_digit = lhs.get_digit(pos).unwrap_or(0);
-        let rhs_digit = rhs.get_digit(pos).unwrap_or(0);
-
-        match lhs_digit.cmp(&rhs_digit) {
-            Ordering::Equal => (),
-            ord => return ord,
-        }
+    match lhs.msd_pos().cmp(&rhs.msd_pos()) {
+        Ordering::Equal => (),
+        ord => return ord,
     }
 
-    Ordering::Equal
+    let (lhs_limbs, rhs_limbs, _) = lhs.aligned_limbs(rhs);
+    limbs_cmp(&lhs_limbs, &rhs_limbs)
 }
 
 pub fn is_unsigned_finite_eq<const RADIX: u32, const PREC: isize>(
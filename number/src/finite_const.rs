@@ -0,0 +1,179 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate::finite::{Finite, FiniteResult};
+use crate::finite_math::{finite_exp, finite_sqrt, is_negligible};
+use crate::finite_ops::{finite_add, finite_div, finite_mul, finite_round_frac};
+use crate::rounding::RoundingMode;
+use crate::status::Status;
+
+/// Radix and name of constant.
+type CacheKey = (u32, &'static str);
+
+/// Positive constant computed with precision `prec`.
+struct CachedConst {
+    prec: isize,
+    limbs: Vec<u32>,
+    exp: isize,
+}
+
+thread_local! {
+    static CACHE: RefCell<HashMap<CacheKey, CachedConst>> = RefCell::new(HashMap::new());
+}
+
+/// Takes constant from the cache of constants of the same radix and rounds it to `prec`
+/// with the current rounding mode.
+///
+/// Only the most precise value of each constant is kept, the constant is computed
+/// and cached again if it is missing or less precise than requested.
+fn cached<const RADIX: u32, const PREC: isize>(
+    name: &'static str,
+    prec: isize,
+    compute: fn(isize) -> FiniteResult<RADIX, PREC>,
+) -> FiniteResult<RADIX, PREC> {
+    let key = (RADIX, name);
+
+    let cached = CACHE.with(|cache| {
+        cache
+            .borrow()
+            .get(&key)
+            .filter(|cached| cached.prec >= prec)
+            .map(|cached| Finite::from_limbs(cached.limbs.clone(), cached.exp))
+    });
+
+    let val = match cached {
+        Some(val) => val,
+        None => {
+            let val = compute(prec)?;
+            let cached = CachedConst {
+                prec,
+                limbs: val.limbs().to_vec(),
+                exp: val.lsd_pos(),
+            };
+
+            CACHE.with(|cache| cache.borrow_mut().insert(key, cached));
+            val
+        }
+    };
+
+    // Constants are rounded to `prec` with the current rounding mode,
+    // whether they are computed or taken from the cache
+    let frac_len = prec - 1 - val.msd_pos();
+    Status::isolated(|| finite_round_frac(val, frac_len, RoundingMode::current(), prec))
+}
+
+/// Computes `atan(1 / x)` by Taylor series.
+fn atan_recip<const RADIX: u32, const PREC: isize>(
+    x: u64,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let x_sq = Finite::from(x * x);
+
+    let mut power = finite_div(Finite::one(), Finite::from(x), prec)?;
+    let mut sum = power.clone();
+    let mut n = 1;
+
+    loop {
+        power = finite_div(power, x_sq.clone(), prec)?;
+        let term = finite_div(power.clone(), Finite::from(2 * n + 1), prec)?;
+
+        if is_negligible(&term, &sum, prec) {
+            break;
+        }
+
+        sum = match n % 2 {
+            0 => finite_add(sum, term, prec)?,
+            _ => finite_add(sum, term.reverse_sign(), prec)?,
+        };
+        n += 1;
+    }
+
+    Ok(sum)
+}
+
+/// Computes `π` by Machin's formula `π = 16 * atan(1/5) - 4 * atan(1/239)`.
+fn machin_pi<const RADIX: u32, const PREC: isize>(prec: isize) -> FiniteResult<RADIX, PREC> {
+    let prec = prec + 2;
+
+    let first = finite_mul(Finite::from(16), atan_recip(5, prec)?, prec)?;
+    let second = finite_mul(Finite::from(4), atan_recip(239, prec)?, prec)?;
+
+    finite_add(first, second.reverse_sign(), prec)
+}
+
+/// Returns `π`.
+pub fn finite_pi<const RADIX: u32, const PREC: isize>(prec: isize) -> FiniteResult<RADIX, PREC> {
+    cached("pi", prec, machin_pi)
+}
+
+/// Returns `τ = 2π`.
+pub fn finite_tau<const RADIX: u32, const PREC: isize>(prec: isize) -> FiniteResult<RADIX, PREC> {
+    cached("tau", prec, |prec| {
+        finite_mul(finite_pi(prec)?, Finite::from(2), prec)
+    })
+}
+
+/// Returns Euler's number `e`.
+pub fn finite_e<const RADIX: u32, const PREC: isize>(prec: isize) -> FiniteResult<RADIX, PREC> {
+    cached("e", prec, |prec| finite_exp(Finite::one(), prec))
+}
+
+/// Returns golden ratio `φ = (1 + sqrt(5)) / 2`.
+pub fn finite_phi<const RADIX: u32, const PREC: isize>(prec: isize) -> FiniteResult<RADIX, PREC> {
+    cached("phi", prec, |prec| {
+        let sum = finite_add(Finite::one(), finite_sqrt(Finite::from(5), prec)?, prec)?;
+        finite_div(sum, Finite::from(2), prec)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cached_prec(radix: u32, name: &'static str) -> Option<isize> {
+        CACHE.with(|cache| cache.borrow().get(&(radix, name)).map(|cached| cached.prec))
+    }
+
+    #[test]
+    fn keeps_most_precise_constant() {
+        let pi_50 = finite_pi::<10, 50>(50).ok().unwrap();
+        assert_eq!(cached_prec(10, "pi"), Some(50));
+
+        let pi_20 = finite_pi::<10, 20>(20).ok().unwrap();
+        assert_eq!(cached_prec(10, "pi"), Some(50));
+        assert_eq!(pi_20.msd_pos() - pi_20.lsd_pos() + 1, 20);
+        assert_eq!(pi_50.msd_pos() - pi_50.lsd_pos() + 1, 50);
+
+        let computed = machin_pi::<10, 20>(20).ok().unwrap();
+        let frac_len = 20 - 1 - computed.msd_pos();
+        let computed = finite_round_frac(computed, frac_len, RoundingMode::HalfEven, 20)
+            .ok()
+            .unwrap();
+        assert_eq!(pi_20.limbs(), computed.limbs());
+
+        finite_pi::<10, 80>(80).ok().unwrap();
+        assert_eq!(cached_prec(10, "pi"), Some(80));
+        assert_eq!(cached_prec(2, "pi"), None);
+
+        finite_pi::<2, 80>(80).ok().unwrap();
+        assert_eq!(cached_prec(2, "pi"), Some(80));
+    }
+    #[test]
+    fn rounds_constant_with_current_mode() {
+        let pi_with = |mode: RoundingMode| {
+            let prev = mode.set_current();
+            let pi = finite_pi::<10, 20>(20).ok().unwrap();
+            prev.set_current();
+
+            pi.limbs().to_vec()
+        };
+
+        let floor = Finite::<10, 20>::try_from("3.1415926535897932384").unwrap();
+        let ceiling = Finite::<10, 20>::try_from("3.1415926535897932385").unwrap();
+
+        assert_eq!(pi_with(RoundingMode::Floor), floor.limbs());
+        assert_eq!(pi_with(RoundingMode::TowardZero), floor.limbs());
+        assert_eq!(pi_with(RoundingMode::HalfEven), floor.limbs());
+        assert_eq!(pi_with(RoundingMode::Ceiling), ceiling.limbs());
+    }
+}
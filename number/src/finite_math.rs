@@ -0,0 +1,466 @@
+use crate::exp_range::ExpRange;
+use crate::finite::{Finite, FiniteOverflow, FiniteResult};
+use crate::finite_ops::{finite_add, finite_div, finite_mul};
+use crate::sign::Sign;
+
+/// Extra digits used for intermediate results of approximated functions.
+pub const GUARD_DIGITS: isize = 10;
+
+/// Returns count of radix digits needed to hold `val`.
+fn digits_count<const RADIX: u32>(val: f64) -> isize {
+    val.abs().log(RADIX as f64).ceil().max(0f64) as isize + 1
+}
+
+/// Checks if series term is too small to change digits of the sum that fit precision.
+pub fn is_negligible<const RADIX: u32, const PREC: isize>(
+    term: &Finite<RADIX, PREC>,
+    sum: &Finite<RADIX, PREC>,
+    prec: isize,
+) -> bool {
+    term.is_zero() || term.msd_pos() < sum.msd_pos() - prec
+}
+
+/// Raises number to unsigned integer power by squaring.
+pub fn finite_powu<const RADIX: u32, const PREC: isize>(
+    mut base: Finite<RADIX, PREC>,
+    mut exp: u64,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let mut res = Finite::one();
+
+    while exp != 0 {
+        if exp % 2 == 1 {
+            res = finite_mul(res, base.clone(), prec)?;
+        }
+
+        exp /= 2;
+
+        if exp != 0 {
+            base = finite_mul(base.clone(), base, prec)?;
+        }
+    }
+
+    Ok(res)
+}
+
+/// Raises number to non-negative integer power.
+///
+/// The power is processed digit by digit, so it may be arbitrary long.
+pub fn finite_powi<const RADIX: u32, const PREC: isize>(
+    mut base: Finite<RADIX, PREC>,
+    exp: &Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let mut res = Finite::one();
+    let end_bound = exp.end_bound();
+
+    for pos in 0..end_bound {
+        let digit = exp.get_digit(pos).unwrap_or(0);
+
+        if digit != 0 {
+            res = finite_mul(res, finite_powu(base.clone(), digit as u64, prec)?, prec)?;
+        }
+
+        if pos + 1 < end_bound {
+            base = finite_powu(base, RADIX as u64, prec)?;
+        }
+    }
+
+    Ok(res)
+}
+
+/// Computes `e^num`.
+///
+/// The argument is divided by `2^halvings` so that the Taylor series converges
+/// fast, then the sum is squared `halvings` times.
+pub fn finite_exp<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    if num.is_zero() {
+        return Ok(Finite::one());
+    }
+
+    if num.is_neg() {
+        return match finite_exp(num.reverse_sign(), prec) {
+            Ok(exp) => finite_div(Finite::one(), exp, prec),
+            Err(_) => Ok(Finite::zero()),
+        };
+    }
+
+    let max_arg = (ExpRange::current().emax() + 1) as f64 * (RADIX as f64).ln();
+
+    if num.approx() > max_arg {
+        return Err(FiniteOverflow { sign: Sign::Pos });
+    }
+
+    let reduction = ((prec as f64).sqrt() / 2f64).max(1f64) as isize;
+    let halvings = (((num.msd_pos() + 1 + reduction) as f64) * (RADIX as f64).log2())
+        .ceil()
+        .max(0f64) as u64;
+    let prec = prec + (halvings as f64 / (RADIX as f64).log2()).ceil() as isize + 1;
+
+    let divisor = finite_powu(Finite::from(2), halvings, prec)?;
+    let arg = finite_div(num, divisor, prec)?;
+
+    let mut sum = Finite::one();
+    let mut term = Finite::one();
+    let mut n = 1;
+
+    loop {
+        term = finite_div(finite_mul(term, arg.clone(), prec)?, Finite::from(n), prec)?;
+
+        if is_negligible(&term, &sum, prec) {
+            break;
+        }
+
+        sum = finite_add(sum, term.clone(), prec)?;
+        n += 1;
+    }
+
+    for _ in 0..halvings {
+        sum = finite_mul(sum.clone(), sum, prec)?;
+    }
+
+    Ok(sum)
+}
+
+/// Returns count of correct radix digits of `f64` approximation.
+pub fn approx_correct_digits<const RADIX: u32>() -> isize {
+    digits_count::<RADIX>(f64::EPSILON.recip()) - 3
+}
+
+/// Computes natural logarithm of number in range `[1, RADIX]` or close to one.
+///
+/// Refines `f64` approximation by Halley's iterations
+/// `y = y + 2 * (num - e^y) / (num + e^y)`, each of them triples correct digits.
+fn reduced_ln<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let mut res = Finite::from_approx(num.approx_ln());
+    let mut correct_digits = approx_correct_digits::<RADIX>();
+
+    while correct_digits < prec {
+        let exp = finite_exp(res.clone(), prec)?;
+        let diff = finite_add(num.clone(), exp.clone().reverse_sign(), prec)?;
+        let sum = finite_add(num.clone(), exp, prec)?;
+        let correction = finite_div(finite_add(diff.clone(), diff, prec)?, sum, prec)?;
+
+        res = finite_add(res, correction, prec)?;
+        correct_digits *= 3;
+    }
+
+    Ok(res)
+}
+
+/// Computes natural logarithm of positive number.
+///
+/// The number is split into `m * RADIX^k`, where `m` is in range `[1, RADIX)`,
+/// so `ln(num) = ln(m) + k * ln(RADIX)`.
+/// Logarithm of number close to one is small, so it is computed without the split
+/// and with more digits, as leading digits of `num - 1` cancel in iterations.
+pub fn finite_ln<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    debug_assert!(
+        !num.is_neg() && !num.is_zero(),
+        "logarithm of non-positive number"
+    );
+
+    if num.is_one() {
+        return Ok(Finite::zero());
+    }
+
+    if num.msd_pos() == 0 || num.msd_pos() == -1 {
+        let exact_prec = prec.max(2 - num.lsd_pos());
+        let diff = finite_add(num.clone(), Finite::one().reverse_sign(), exact_prec)?;
+        let lost_len = -diff.msd_pos();
+
+        if lost_len > 0 {
+            return reduced_ln(num, prec + lost_len);
+        }
+    }
+
+    let k = num.msd_pos();
+    let ln_m = reduced_ln(num.shift_point(-k), prec)?;
+
+    if k == 0 {
+        return Ok(ln_m);
+    }
+
+    let k_prec = prec + digits_count::<RADIX>(k as f64);
+    let ln_radix = reduced_ln(Finite::from(RADIX as u64), k_prec)?;
+
+    let k_sign = if k < 0 { Sign::Neg } else { Sign::Pos };
+    let k = Finite::from(k.unsigned_abs() as u64).set_sign(&k_sign);
+
+    finite_add(ln_m, finite_mul(ln_radix, k, k_prec)?, prec)
+}
+
+/// Computes logarithm of positive number to positive base other than one
+/// as `ln(num) / ln(base)`.
+pub fn finite_log<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    base: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    debug_assert!(!base.is_one(), "logarithm to base one");
+
+    finite_div(finite_ln(num, prec)?, finite_ln(base, prec)?, prec)
+}
+
+/// Computes square root of non-negative number.
+///
+/// The number is split into `m * RADIX^(2k)`, where `m` is in range `[1, RADIX^2)`,
+/// and `f64` approximation of `sqrt(m)` is refined by Newton's iterations
+/// `y = (y + m / y) / 2`.
+pub fn finite_sqrt<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    debug_assert!(
+        !num.is_neg() || num.is_zero(),
+        "square root of negative number"
+    );
+
+    if num.is_zero() {
+        return Ok(num);
+    }
+
+    let k = num.msd_pos().div_euclid(2);
+    let m = num.shift_point(-2 * k);
+
+    let mut res = Finite::from_approx(m.approx().sqrt());
+    let mut correct_digits = approx_correct_digits::<RADIX>();
+
+    while correct_digits < prec {
+        let sum = finite_add(res.clone(), finite_div(m.clone(), res, prec)?, prec)?;
+        res = finite_div(sum, Finite::from(2), prec)?;
+        correct_digits *= 2;
+    }
+
+    Ok(res.shift_point(k))
+}
+
+/// Computes `n`-th root of non-negative number.
+///
+/// The number is split into `m * RADIX^(nk)`, where `m` is in range `[1, RADIX^n)`,
+/// and `f64` approximation of the root of `m` is refined by Newton's iterations
+/// `y = ((n - 1) * y + m / y^(n - 1)) / n`.
+/// Roots of degree greater than precision are computed as `e^(ln(num) / n)`.
+pub fn finite_nth_root<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    n: u64,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    debug_assert!(!num.is_neg() || num.is_zero(), "root of negative number");
+    debug_assert!(n != 0, "root of zero degree");
+
+    if num.is_zero() || n == 1 {
+        return Ok(num);
+    }
+
+    if n == 2 {
+        return finite_sqrt(num, prec);
+    }
+
+    if n > prec as u64 {
+        let ln = finite_ln(num, prec)?;
+        return finite_exp(finite_div(ln, Finite::from(n), prec)?, prec);
+    }
+
+    let degree = n as isize;
+    let prec = prec + degree;
+
+    let k = num.msd_pos().div_euclid(degree);
+    let m = num.shift_point(-degree * k);
+
+    let mut res = Finite::from_approx((m.approx_ln() / n as f64).exp());
+    let mut correct_digits = approx_correct_digits::<RADIX>();
+
+    while correct_digits < prec {
+        let quotient = finite_div(m.clone(), finite_powu(res.clone(), n - 1, prec)?, prec)?;
+        let sum = finite_add(finite_mul(res, Finite::from(n - 1), prec)?, quotient, prec)?;
+
+        res = finite_div(sum, Finite::from(n), prec)?;
+        correct_digits *= 2;
+    }
+
+    Ok(res.shift_point(k))
+}
+
+/// Computes `e^num` and `e^-num` of non-negative number.
+fn exp_pair<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> Result<(Finite<RADIX, PREC>, Finite<RADIX, PREC>), FiniteOverflow> {
+    let exp = finite_exp(num, prec)?;
+    let recip = finite_div(Finite::one(), exp.clone(), prec)?;
+
+    Ok((exp, recip))
+}
+
+/// Checks if square of number is negligible against one.
+fn is_tiny<const RADIX: u32, const PREC: isize>(num: &Finite<RADIX, PREC>, prec: isize) -> bool {
+    num.msd_pos().saturating_mul(2) < -prec
+}
+
+/// Computes `num + num^3 / divisor` of tiny number,
+/// further terms of Taylor series are negligible.
+fn tiny_series<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    divisor: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let cube = finite_powu(num.clone(), 3, prec)?;
+    finite_add(num, finite_div(cube, divisor, prec)?, prec)
+}
+
+/// Adds digits lost by cancellation with one for number less than one by modulus.
+fn prec_for_small<const RADIX: u32, const PREC: isize>(
+    num: &Finite<RADIX, PREC>,
+    prec: isize,
+) -> isize {
+    prec + (-num.msd_pos()).max(0)
+}
+
+/// Computes hyperbolic sine as `(e^num - e^-num) / 2`.
+pub fn finite_sinh<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    if is_tiny(&num, prec) {
+        return tiny_series(num, Finite::from(6), prec);
+    }
+
+    let prec = prec_for_small(&num, prec);
+    let sign = if num.is_neg() { Sign::Neg } else { Sign::Pos };
+
+    let (exp, recip) = exp_pair(num.set_sign(&Sign::Pos), prec)
+        .map_err(|_| FiniteOverflow { sign: sign.clone() })?;
+    let diff = finite_add(exp, recip.reverse_sign(), prec)?;
+
+    Ok(finite_div(diff, Finite::from(2), prec)?.set_sign(&sign))
+}
+
+/// Computes hyperbolic cosine as `(e^num + e^-num) / 2`.
+pub fn finite_cosh<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let (exp, recip) = exp_pair(num.set_sign(&Sign::Pos), prec)?;
+    finite_div(finite_add(exp, recip, prec)?, Finite::from(2), prec)
+}
+
+/// Computes hyperbolic tangent as `(e^2num - 1) / (e^2num + 1)`.
+///
+/// Hyperbolic tangent of number whose exponent overflows is `±1`.
+pub fn finite_tanh<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    if is_tiny(&num, prec) {
+        return tiny_series(num, Finite::from(3).reverse_sign(), prec);
+    }
+
+    let prec = prec_for_small(&num, prec);
+    let sign = if num.is_neg() { Sign::Neg } else { Sign::Pos };
+    let num = num.set_sign(&Sign::Pos);
+
+    let exp = match finite_add(num.clone(), num, prec).and_then(|num| finite_exp(num, prec)) {
+        Ok(exp) => exp,
+        Err(_) => return Ok(Finite::one().set_sign(&sign)),
+    };
+
+    let numerator = finite_add(exp.clone(), Finite::one().reverse_sign(), prec)?;
+    let denominator = finite_add(exp, Finite::one(), prec)?;
+
+    Ok(finite_div(numerator, denominator, prec)?.set_sign(&sign))
+}
+
+/// Computes `ln(num + sqrt(num^2 + shift))` of non-negative number.
+///
+/// If square of number overflows, `shift` is negligible,
+/// so the result is computed as `ln(2) + ln(num)`.
+fn ln_of_hypot<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    shift: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let num_sq = match finite_mul(num.clone(), num.clone(), prec) {
+        Ok(num_sq) => num_sq,
+        Err(_) => {
+            return finite_add(
+                finite_ln(Finite::from(2), prec)?,
+                finite_ln(num, prec)?,
+                prec,
+            )
+        }
+    };
+
+    let root = finite_sqrt(finite_add(num_sq, shift, prec)?, prec)?;
+    finite_ln(finite_add(num, root, prec)?, prec)
+}
+
+/// Computes inverse hyperbolic sine as `ln(num + sqrt(num^2 + 1))`.
+pub fn finite_asinh<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    if is_tiny(&num, prec) {
+        return tiny_series(num, Finite::from(6).reverse_sign(), prec);
+    }
+
+    let prec = prec_for_small(&num, prec);
+    let sign = if num.is_neg() { Sign::Neg } else { Sign::Pos };
+    let res = ln_of_hypot(num.set_sign(&Sign::Pos), Finite::one(), prec)?;
+
+    Ok(res.set_sign(&sign))
+}
+
+/// Computes inverse hyperbolic cosine of number not less than one
+/// as `ln(num + sqrt(num^2 - 1))`.
+///
+/// Near one it is computed as `ln(1 + d + sqrt(d * (d + 2)))`, where `d = num - 1`,
+/// with more digits, as leading digits of `num^2 - 1` and of the result cancel.
+pub fn finite_acosh<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let exact_prec = prec.max(2 - num.lsd_pos());
+    let diff = finite_add(num.clone(), Finite::one().reverse_sign(), exact_prec)?;
+    let lost_len = -diff.msd_pos();
+
+    if diff.is_zero() {
+        return Ok(diff);
+    }
+
+    if lost_len <= 0 {
+        return ln_of_hypot(num, Finite::one().reverse_sign(), prec);
+    }
+
+    let prec = prec + lost_len;
+    let product = finite_mul(diff.clone(), finite_add(diff, Finite::from(2), prec)?, prec)?;
+
+    finite_ln(finite_add(num, finite_sqrt(product, prec)?, prec)?, prec)
+}
+
+/// Computes inverse hyperbolic tangent of number in range `(-1, 1)`
+/// as `ln((1 + num) / (1 - num)) / 2`.
+pub fn finite_atanh<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    if is_tiny(&num, prec) {
+        return tiny_series(num, Finite::from(3), prec);
+    }
+
+    let prec = prec_for_small(&num, prec);
+    let numerator = finite_add(Finite::one(), num.clone(), prec)?;
+    let denominator = finite_add(Finite::one(), num.reverse_sign(), prec)?;
+    let ln = finite_ln(finite_div(numerator, denominator, prec)?, prec)?;
+
+    finite_div(ln, Finite::from(2), prec)
+}
@@ -1,88 +1,179 @@
+use crate::exp_range::ExpRange;
 use crate::finite::{Finite, FiniteOverflow};
-use crate::finite_cmp::{finite_cmp, get_unsigned_finite_max_min, is_unsigned_finite_eq};
+use crate::finite_cmp::{get_unsigned_finite_max_min, is_unsigned_finite_eq, unsigned_finite_cmp};
+use crate::limbs::{limbs_add, limbs_cmp, limbs_div_rem, limbs_mul, limbs_shl_digits, limbs_sub};
+use crate::number_spec::prec_of;
+use crate::rounding::RoundingMode;
 use crate::sign::Sign;
+use crate::status::Status;
 use std::cmp::Ordering;
 
+/// Returns overflow if the most significant digit is above the current exponent range.
 fn check_to_overflow<const RADIX: u32, const PREC: isize>(
     num: &Finite<RADIX, PREC>,
 ) -> Result<(), FiniteOverflow> {
-    match num.len().checked_add(1) {
-        None if num.is_neg() => Err(FiniteOverflow { sign: Sign::Neg }),
-        None => Err(FiniteOverflow { sign: Sign::Pos }),
-        Some(len) if len > PREC && num.is_neg() => Err(FiniteOverflow { sign: Sign::Neg }),
-        Some(len) if len > PREC => Err(FiniteOverflow { sign: Sign::Pos }),
-        Some(_) => Ok(()),
+    match num.is_zero() || num.msd_pos() <= ExpRange::current().emax() {
+        true => Ok(()),
+        false if num.is_neg() => Err(FiniteOverflow { sign: Sign::Neg }),
+        false => Err(FiniteOverflow { sign: Sign::Pos }),
     }
 }
 
-fn unsigned_add<const RADIX: u32, const PREC: isize>(
-    lhs: Finite<RADIX, PREC>,
-    rhs: Finite<RADIX, PREC>,
+/// Returns position of the least significant digit that fits precision
+/// for number whose most significant digit is at `msd_pos`.
+///
+/// Numbers below the current exponent range keep fewer significant digits.
+fn lowest_pos_of(msd_pos: isize, prec: isize) -> isize {
+    (msd_pos - (prec - 1)).max(ExpRange::current().etiny(prec))
+}
+
+/// Rounds number to significant digits that fit precision with the current rounding mode.
+///
+/// Returns overflow if number is above the current exponent range.
+pub fn fit_to_prec<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
 ) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
-    check_to_overflow(&lhs)?;
+    if num.is_zero() {
+        return Ok(num);
+    }
+
+    let lowest_pos = lowest_pos_of(num.msd_pos(), prec);
 
-    let mut new_num = Finite::zero();
-    let mut buffer = 0;
+    if num.lsd_pos() >= lowest_pos {
+        check_to_overflow(&num)?;
+        return Ok(num);
+    }
 
-    for pos in lhs.merge_bounds(&rhs) {
-        let lhs_digit = lhs.get_digit(pos).unwrap_or(0);
-        let rhs_digit = rhs.get_digit(pos).unwrap_or(0);
+    finite_round_frac(num, -lowest_pos, RoundingMode::current(), prec)
+}
 
-        let sum = buffer + lhs_digit + rhs_digit;
+/// Rounds number truncated to `frac_len` fractional digits.
+///
+/// `discarded` is the order of the discarded part relative to half of ulp,
+/// it is `None` if the discarded part is zero.
+fn round_truncated<const RADIX: u32, const PREC: isize>(
+    truncated: Finite<RADIX, PREC>,
+    sign: &Sign,
+    frac_len: isize,
+    discarded: Option<Ordering>,
+    mode: RoundingMode,
+    prec: isize,
+) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
+    let is_neg = matches!(sign, Sign::Neg);
+
+    let away_from_zero = match (mode, discarded) {
+        (_, None) | (RoundingMode::TowardZero, _) => false,
+        (RoundingMode::Floor, _) => is_neg,
+        (RoundingMode::Ceiling, _) => !is_neg,
+        (RoundingMode::HalfUp, Some(ord)) => ord != Ordering::Less,
+        (RoundingMode::HalfDown, Some(ord)) => ord == Ordering::Greater,
+        (RoundingMode::HalfEven, Some(Ordering::Equal)) => {
+            truncated.clone().shift_point(frac_len).is_odd()
+        }
+        (RoundingMode::HalfEven, Some(ord)) => ord == Ordering::Greater,
+    };
 
-        new_num.set_digit(sum % RADIX, pos);
-        buffer = sum / RADIX;
+    // Only rounding to precision of numbers raises flags, computations with guard digits do not
+    if discarded.is_some() && prec <= prec_of::<PREC>() {
+        match truncated.is_zero() || truncated.msd_pos() < ExpRange::current().emin() {
+            true => Status::raise_underflow(),
+            false => Status::raise_inexact(),
+        }
     }
 
-    if buffer != 0 {
-        new_num.set_digit(buffer % RADIX, lhs.end_bound());
+    let truncated = truncated.set_sign(sign);
+
+    if !away_from_zero {
+        check_to_overflow(&truncated)?;
+        return Ok(truncated);
     }
 
-    Ok(new_num.trim_zeros().set_sign_of(&lhs))
+    let ulp = Finite::one().shift_point(-frac_len).set_sign(sign);
+    finite_add(truncated, ulp, prec)
 }
 
-fn unsigned_sub<const RADIX: u32, const PREC: isize>(
+fn unsigned_add<const RADIX: u32, const PREC: isize>(
     lhs: Finite<RADIX, PREC>,
     rhs: Finite<RADIX, PREC>,
-) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
-    let mut new_num = Finite::zero();
-    let mut borrowing = 0;
+) -> Finite<RADIX, PREC> {
+    if rhs.is_zero() {
+        return lhs;
+    }
+
+    if lhs.is_zero() {
+        return rhs.set_sign_of(&lhs);
+    }
 
-    for pos in lhs.merge_bounds(&rhs) {
-        let lhs_digit = lhs.get_digit(pos).unwrap_or(0);
-        let rhs_digit = rhs.get_digit(pos).unwrap_or(0);
+    let (lhs_limbs, rhs_limbs, exp) = lhs.aligned_limbs(&rhs);
 
-        let subtract = if lhs_digit < rhs_digit + borrowing {
-            let sub = RADIX + lhs_digit - rhs_digit - borrowing;
-            borrowing = 1;
-            sub
-        } else {
-            let sub = lhs_digit - rhs_digit - borrowing;
-            borrowing = 0;
-            sub
-        };
+    Finite::from_limbs(limbs_add::<RADIX>(&lhs_limbs, &rhs_limbs), exp).set_sign_of(&lhs)
+}
 
-        new_num.set_digit(subtract, pos);
+fn unsigned_sub<const RADIX: u32, const PREC: isize>(
+    lhs: Finite<RADIX, PREC>,
+    rhs: Finite<RADIX, PREC>,
+) -> Finite<RADIX, PREC> {
+    if rhs.is_zero() {
+        return lhs;
     }
 
-    if borrowing != 0 {
+    let (lhs_limbs, rhs_limbs, exp) = lhs.aligned_limbs(&rhs);
+
+    if let Ordering::Less = limbs_cmp(&lhs_limbs, &rhs_limbs) {
         panic!("reduced number is less than subtracted number");
     }
 
-    Ok(new_num.trim_zeros().set_sign_of(&lhs))
+    Finite::from_limbs(limbs_sub::<RADIX>(&lhs_limbs, &rhs_limbs), exp).set_sign_of(&lhs)
+}
+
+/// Replaces addend that is too small to change digits of the sum that fit precision
+/// by one digit placed below them.
+///
+/// The sum is rounded the same way, but it is not spread over all positions
+/// between the addends.
+fn to_sticky<const RADIX: u32, const PREC: isize>(
+    umin: Finite<RADIX, PREC>,
+    umax: &Finite<RADIX, PREC>,
+    prec: isize,
+) -> Finite<RADIX, PREC> {
+    let sticky_pos = umax.lsd_pos().min(umax.msd_pos() - prec) - 2;
+
+    match umin.msd_pos() < sticky_pos {
+        true => Finite::one().shift_point(sticky_pos).set_sign_of(&umin),
+        false => umin,
+    }
+}
+
+/// Returns exact zero sum of numbers of opposite signs,
+/// it is negative only if rounding toward negative infinity.
+fn exact_zero_sum<const RADIX: u32, const PREC: isize>() -> Finite<RADIX, PREC> {
+    match RoundingMode::current() {
+        RoundingMode::Floor => Finite::zero().set_sign(&Sign::Neg),
+        _ => Finite::zero(),
+    }
 }
 
 pub fn finite_add<const RADIX: u32, const PREC: isize>(
     lhs: Finite<RADIX, PREC>,
     rhs: Finite<RADIX, PREC>,
+    prec: isize,
 ) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
     let (umax, umin) = get_unsigned_finite_max_min(lhs, rhs);
 
-    match umax.cmp_sign(&umin) {
+    if umin.is_zero() && !umax.is_zero() {
+        return fit_to_prec(umax, prec);
+    }
+
+    let umin = to_sticky(umin, &umax, prec);
+
+    let sum = match umax.cmp_sign(&umin) {
         Ordering::Equal => unsigned_add(umax, umin),
-        _ if is_unsigned_finite_eq(&umax, &umin) => Ok(Finite::zero()),
+        _ if is_unsigned_finite_eq(&umax, &umin) => return Ok(exact_zero_sum()),
         _ => unsigned_sub(umax, umin),
-    }
+    };
+
+    fit_to_prec(sum, prec)
 }
 
 pub fn finite_neg<const RADIX: u32, const PREC: isize>(
@@ -91,48 +182,19 @@ pub fn finite_neg<const RADIX: u32, const PREC: isize>(
     num.reverse_sign()
 }
 
-fn unsigned_mul_to_digit<const RADIX: u32, const PREC: isize>(
-    num: &Finite<RADIX, PREC>,
-    digit: u32,
-) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
-    check_to_overflow(&num)?;
-
-    let mut new_num = Finite::zero();
-    let mut buffer = 0;
-
-    for pos in num.bounds() {
-        let self_digit = num.get_digit(pos).unwrap();
-        let mul = self_digit * digit + buffer;
-
-        new_num.set_digit(mul % RADIX, pos);
-        buffer = mul / RADIX;
-    }
-
-    if buffer != 0 {
-        new_num.set_digit(buffer, new_num.end_bound());
-    }
-
-    Ok(new_num.trim_zeros())
-}
-
+/// Multiplies significands of numbers, the product is exact.
 fn unsigned_mul<const RADIX: u32, const PREC: isize>(
     lhs: Finite<RADIX, PREC>,
     rhs: Finite<RADIX, PREC>,
-) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
-    let mut new_num = Finite::zero();
-
-    for pos in rhs.bounds() {
-        let rhs_digit = rhs.get_digit(pos).unwrap();
-        let mul = unsigned_mul_to_digit(&lhs, rhs_digit)?;
-        new_num = unsigned_add(new_num, mul)?;
-    }
-
-    Ok(new_num.trim_zeros())
+) -> Finite<RADIX, PREC> {
+    let product = limbs_mul::<RADIX>(lhs.limbs(), rhs.limbs());
+    Finite::from_limbs(product, lhs.lsd_pos() + rhs.lsd_pos())
 }
 
 pub fn finite_mul<const RADIX: u32, const PREC: isize>(
     lhs: Finite<RADIX, PREC>,
     rhs: Finite<RADIX, PREC>,
+    prec: isize,
 ) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
     let res_sign = match lhs.cmp_sign(&rhs) {
         Ordering::Equal => Sign::Pos,
@@ -143,48 +205,59 @@ pub fn finite_mul<const RADIX: u32, const PREC: isize>(
         return Ok(Finite::zero().set_sign(&res_sign));
     }
 
-    unsigned_mul(lhs, rhs)
-        .map(|n| n.set_sign(&res_sign))
-        .map_err(|e| e.set_sign(&res_sign))
+    fit_to_prec(unsigned_mul(lhs, rhs).set_sign(&res_sign), prec)
 }
 
-fn unsigned_div<const RADIX: u32, const PREC: isize>(
-    mut lhs: Finite<RADIX, PREC>,
-    rhs: Finite<RADIX, PREC>,
-) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
-    let mut delta_len = lhs.len() - rhs.len();
-    let mut quotient: Finite<RADIX, PREC> = Finite::zero();
-
-    loop {
-        let shifted_rhs = rhs.clone().shift_point(delta_len);
+/// Returns position of the most significant digit of quotient of non-zero numbers.
+fn quotient_msd_pos<const RADIX: u32, const PREC: isize>(
+    lhs: &Finite<RADIX, PREC>,
+    rhs: &Finite<RADIX, PREC>,
+) -> isize {
+    let msd_diff = lhs.msd_pos() - rhs.msd_pos();
 
-        if let Ordering::Less = finite_cmp(&lhs, &shifted_rhs) {
-            delta_len -= 1;
-            continue;
-        }
+    match unsigned_finite_cmp(lhs, &rhs.clone().shift_point(msd_diff)) {
+        Ordering::Less => msd_diff - 1,
+        _ => msd_diff,
+    }
+}
 
-        let subtract = unsigned_sub(lhs, shifted_rhs)?;
+/// Divides numbers, computes quotient digits down to `lowest_pos`.
+///
+/// Returns the truncated quotient, count of its fractional digits and the remainder.
+fn unsigned_div<const RADIX: u32, const PREC: isize>(
+    lhs: Finite<RADIX, PREC>,
+    rhs: Finite<RADIX, PREC>,
+    lowest_pos: isize,
+) -> (Finite<RADIX, PREC>, isize, Finite<RADIX, PREC>) {
+    // lhs / rhs = (lhs_sig / rhs_sig) * RADIX^shift * RADIX^lowest_pos
+    let shift = lhs.lsd_pos() - rhs.lsd_pos() - lowest_pos;
 
-        let next_quotient = unsigned_add(quotient.clone(), Finite::one().shift_point(delta_len))?;
+    let (quotient, rem, rem_exp) = match shift >= 0 {
+        true => {
+            let dividend = limbs_shl_digits::<RADIX>(lhs.limbs(), shift.unsigned_abs());
+            let (quotient, rem) = limbs_div_rem::<RADIX>(&dividend, rhs.limbs());
 
-        if let Err(_) = check_to_overflow(&next_quotient) {
-            break;
+            (quotient, rem, rhs.lsd_pos() + lowest_pos)
         }
+        false => {
+            let divisor = limbs_shl_digits::<RADIX>(rhs.limbs(), shift.unsigned_abs());
+            let (quotient, rem) = limbs_div_rem::<RADIX>(lhs.limbs(), &divisor);
 
-        lhs = subtract;
-        quotient = next_quotient;
-
-        if lhs.is_zero() && delta_len < 0 {
-            break;
+            (quotient, rem, lhs.lsd_pos())
         }
-    }
+    };
 
-    Ok(quotient.trim_zeros())
+    (
+        Finite::from_limbs(quotient, lowest_pos),
+        -lowest_pos,
+        Finite::from_limbs(rem, rem_exp),
+    )
 }
 
 pub fn finite_div<const RADIX: u32, const PREC: isize>(
     lhs: Finite<RADIX, PREC>,
     rhs: Finite<RADIX, PREC>,
+    prec: isize,
 ) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
     let res_sign = match lhs.cmp_sign(&rhs) {
         Ordering::Equal => Sign::Pos,
@@ -203,7 +276,133 @@ pub fn finite_div<const RADIX: u32, const PREC: isize>(
         return Ok(lhs.set_sign(&res_sign));
     }
 
-    unsigned_div(lhs, rhs)
-        .map(|n| n.set_sign(&res_sign))
-        .map_err(|e| e.set_sign(&res_sign))
+    let lowest_pos = lowest_pos_of(quotient_msd_pos(&lhs, &rhs), prec);
+    let (quotient, frac_len, rem) = unsigned_div(lhs, rhs.clone(), lowest_pos);
+    let mode = RoundingMode::current();
+
+    let discarded = match rem.is_zero() {
+        true => None,
+        false => Some(unsigned_finite_cmp(
+            &unsigned_add(rem.clone(), rem),
+            &rhs.shift_point(-frac_len),
+        )),
+    };
+
+    round_truncated(quotient, &res_sign, frac_len, discarded, mode, prec)
+}
+
+/// Divides numbers, returns quotient truncated to integer and remainder
+/// that has the sign of dividend. If `is_floor`, the quotient is rounded
+/// toward negative infinity instead and the remainder has the sign of divisor.
+///
+/// The quotient is exact, it is not rounded to precision even if it does not fit it,
+/// so that the remainder is always exact before it is fit to precision.
+pub fn finite_div_rem<const RADIX: u32, const PREC: isize>(
+    lhs: Finite<RADIX, PREC>,
+    rhs: Finite<RADIX, PREC>,
+    is_floor: bool,
+    prec: isize,
+) -> Result<(Finite<RADIX, PREC>, Finite<RADIX, PREC>), FiniteOverflow> {
+    let res_sign = match lhs.cmp_sign(&rhs) {
+        Ordering::Equal => Sign::Pos,
+        _ => Sign::Neg,
+    };
+
+    if rhs.is_zero() {
+        return Err(FiniteOverflow { sign: res_sign });
+    }
+
+    let lhs_sign = if lhs.is_neg() { Sign::Neg } else { Sign::Pos };
+    let rhs_sign = if rhs.is_neg() { Sign::Neg } else { Sign::Pos };
+    let (lhs, rhs) = (lhs.set_sign(&Sign::Pos), rhs.set_sign(&Sign::Pos));
+
+    let (quotient, rem) = match lhs.is_zero() {
+        true => (Finite::zero(), lhs),
+        false => {
+            let (quotient, _, rem) = unsigned_div(lhs, rhs.clone(), 0);
+            (quotient, rem)
+        }
+    };
+
+    let (quotient, rem) = match is_floor && matches!(res_sign, Sign::Neg) && !rem.is_zero() {
+        true => (
+            unsigned_add(quotient, Finite::one()),
+            unsigned_sub(rhs, rem).set_sign(&rhs_sign),
+        ),
+        false => (quotient, rem.set_sign(&lhs_sign)),
+    };
+
+    Ok((quotient.set_sign(&res_sign), fit_to_prec(rem, prec)?))
+}
+
+pub fn finite_trunc<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+) -> Finite<RADIX, PREC> {
+    num.truncate_frac(0)
+}
+
+pub fn finite_floor<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
+    let trunc = finite_trunc(num.clone());
+
+    if num.is_neg() && !is_unsigned_finite_eq(&trunc, &num) {
+        return finite_add(trunc, Finite::one().set_sign(&Sign::Neg), prec);
+    }
+
+    Ok(trunc)
+}
+
+pub fn finite_ceil<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
+    let trunc = finite_trunc(num.clone());
+
+    if !num.is_neg() && !is_unsigned_finite_eq(&trunc, &num) {
+        return finite_add(trunc, Finite::one(), prec);
+    }
+
+    Ok(trunc)
+}
+
+/// Rounds number to `frac_len` fractional digits with given rounding mode.
+pub fn finite_round_frac<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    frac_len: isize,
+    mode: RoundingMode,
+    prec: isize,
+) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
+    let sign = if num.is_neg() { Sign::Neg } else { Sign::Pos };
+    let num = num.set_sign(&Sign::Pos);
+
+    let truncated = num.clone().truncate_frac(frac_len);
+    let rest = unsigned_sub(num, truncated.clone());
+
+    let discarded = match rest.is_zero() {
+        true => None,
+        false => Some(unsigned_finite_cmp(
+            &unsigned_add(rest.clone(), rest),
+            &Finite::one().shift_point(-frac_len),
+        )),
+    };
+
+    round_truncated(truncated, &sign, frac_len, discarded, mode, prec)
+}
+
+/// Rounds number to integer, half away from zero.
+pub fn finite_round<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
+    finite_round_frac(num, 0, RoundingMode::HalfUp, prec)
+}
+
+/// Rounds result computed with extra digits to precision with the current rounding mode.
+pub fn finite_round_to_prec<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> Result<Finite<RADIX, PREC>, FiniteOverflow> {
+    fit_to_prec(num, prec)
 }
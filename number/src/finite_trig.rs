@@ -0,0 +1,360 @@
+use std::cmp::Ordering;
+
+use crate::finite::{Finite, FiniteOverflow, FiniteResult};
+use crate::finite_cmp::unsigned_finite_cmp;
+use crate::finite_const::finite_pi;
+use crate::finite_math::{approx_correct_digits, finite_sqrt, is_negligible};
+use crate::finite_ops::{finite_add, finite_div, finite_mul, finite_round, finite_trunc};
+use crate::sign::Sign;
+
+/// Unit of angles taken and returned by trigonometric functions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AngleUnit {
+    #[default]
+    Radians,
+    Degrees,
+}
+
+impl AngleUnit {
+    /// Returns angle of quarter turn.
+    fn quarter_turn<const RADIX: u32, const PREC: isize>(
+        &self,
+        prec: isize,
+    ) -> FiniteResult<RADIX, PREC> {
+        match self {
+            Self::Radians => finite_div(finite_pi(prec)?, Finite::from(2), prec),
+            Self::Degrees => Ok(Finite::from(90)),
+        }
+    }
+
+    /// Converts angle in this unit to radians.
+    pub(crate) fn angle_to_radians<const RADIX: u32, const PREC: isize>(
+        self,
+        num: Finite<RADIX, PREC>,
+        prec: isize,
+    ) -> FiniteResult<RADIX, PREC> {
+        match self {
+            Self::Radians => Ok(num),
+            Self::Degrees => finite_div(
+                finite_mul(num, finite_pi(prec)?, prec)?,
+                Finite::from(180),
+                prec,
+            ),
+        }
+    }
+
+    /// Converts angle in radians to this unit.
+    pub(crate) fn angle_from_radians<const RADIX: u32, const PREC: isize>(
+        self,
+        num: Finite<RADIX, PREC>,
+        prec: isize,
+    ) -> FiniteResult<RADIX, PREC> {
+        match self {
+            Self::Radians => Ok(num),
+            Self::Degrees => finite_div(
+                finite_mul(num, Finite::from(180), prec)?,
+                finite_pi(prec)?,
+                prec,
+            ),
+        }
+    }
+}
+
+/// Sums Taylor series of sine or cosine starting from `first` term of power `n`.
+fn sin_cos_series<const RADIX: u32, const PREC: isize>(
+    first: Finite<RADIX, PREC>,
+    num_sq: &Finite<RADIX, PREC>,
+    mut n: u64,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let mut sum = first.clone();
+    let mut term = first;
+
+    loop {
+        term = finite_mul(term, num_sq.clone(), prec)?;
+        term = finite_div(term, Finite::from((n + 1) * (n + 2)), prec)?;
+
+        if is_negligible(&term, &sum, prec) {
+            break;
+        }
+
+        term = term.reverse_sign();
+        sum = finite_add(sum, term.clone(), prec)?;
+        n += 2;
+    }
+
+    Ok(sum)
+}
+
+/// Computes sine of number in range `[-π/4, π/4]`.
+fn reduced_sin<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let num_sq = finite_mul(num.clone(), num.clone(), prec)?;
+    sin_cos_series(num, &num_sq, 1, prec)
+}
+
+/// Computes cosine of number in range `[-π/4, π/4]`.
+fn reduced_cos<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let num_sq = finite_mul(num.clone(), num, prec)?;
+    sin_cos_series(Finite::one(), &num_sq, 0, prec)
+}
+
+/// Splits non-negative angle into `q * quarter_turn + rest`,
+/// where `rest` is in range `[-π/4, π/4]` radians.
+///
+/// Returns `rest` converted to radians and quadrant `q mod 4`.
+fn reduce_to_quadrant<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    unit: AngleUnit,
+    prec: isize,
+) -> Result<(Finite<RADIX, PREC>, u64), FiniteOverflow> {
+    let mut work_prec = prec + num.int_len();
+
+    loop {
+        let (rest, quotient) = split_quarter_turns(num.clone(), unit, work_prec)?;
+
+        // Zero and multiples of quarter turn in degrees leave exact zero `rest`,
+        // while in radians it only means all computed digits cancelled
+        let is_exact = rest.is_zero() && (num.is_zero() || unit == AngleUnit::Degrees);
+
+        // Angles close to multiples of quarter turn lose leading digits of `rest`
+        // to cancellation, then it is computed again with more digits
+        let lost_len = match rest.is_zero() {
+            true => work_prec,
+            false => (num.msd_pos() - rest.msd_pos()).max(0),
+        };
+
+        if is_exact || work_prec - lost_len >= prec {
+            let turns = finite_trunc(finite_div(quotient.clone(), Finite::from(4), work_prec)?);
+            let turns = finite_mul(turns, Finite::from(4), work_prec)?;
+            let quadrant = finite_add(quotient, turns.reverse_sign(), work_prec)?;
+
+            return Ok((
+                unit.angle_to_radians(rest, prec)?,
+                quadrant.to_u64().unwrap_or(0),
+            ));
+        }
+
+        work_prec = prec + num.int_len() + lost_len;
+    }
+}
+
+/// Returns `rest` of angle and quotient `q` computed with precision `prec`.
+fn split_quarter_turns<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    unit: AngleUnit,
+    prec: isize,
+) -> Result<(Finite<RADIX, PREC>, Finite<RADIX, PREC>), FiniteOverflow> {
+    let quarter_turn = unit.quarter_turn(prec)?;
+
+    let quotient = finite_round(finite_div(num.clone(), quarter_turn.clone(), prec)?, prec)?;
+    let rest = finite_mul(quotient.clone(), quarter_turn, prec)?;
+    let rest = finite_add(num, rest.reverse_sign(), prec)?;
+
+    Ok((rest, quotient))
+}
+
+/// Reverses sign of non-zero number if `cond` is true.
+///
+/// Zero results of reduced angles are exact, so they stay positive.
+fn negate_if<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    cond: bool,
+) -> Finite<RADIX, PREC> {
+    match cond && !num.is_zero() {
+        true => num.reverse_sign(),
+        false => num,
+    }
+}
+
+/// Computes sine of angle.
+pub fn finite_sin<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    unit: AngleUnit,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let is_neg = num.is_neg();
+    let (rest, quadrant) = reduce_to_quadrant(num.set_sign(&Sign::Pos), unit, prec)?;
+
+    let res = match quadrant % 2 {
+        0 => reduced_sin(rest, prec)?,
+        _ => reduced_cos(rest, prec)?,
+    };
+
+    Ok(negate_if(res, is_neg != (quadrant >= 2)))
+}
+
+/// Computes cosine of angle.
+pub fn finite_cos<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    unit: AngleUnit,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let (rest, quadrant) = reduce_to_quadrant(num.set_sign(&Sign::Pos), unit, prec)?;
+
+    let res = match quadrant % 2 {
+        0 => reduced_cos(rest, prec)?,
+        _ => reduced_sin(rest, prec)?,
+    };
+
+    Ok(negate_if(res, quadrant == 1 || quadrant == 2))
+}
+
+/// Computes tangent of angle.
+///
+/// Returns overflow if cosine of angle is zero.
+pub fn finite_tan<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    unit: AngleUnit,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let is_neg = num.is_neg();
+    let (rest, quadrant) = reduce_to_quadrant(num.set_sign(&Sign::Pos), unit, prec)?;
+
+    let (sin, cos) = (reduced_sin(rest.clone(), prec)?, reduced_cos(rest, prec)?);
+
+    let res = match quadrant % 2 {
+        0 => finite_div(sin, cos, prec),
+        _ => finite_div(cos, negate_if(sin, true), prec),
+    };
+
+    match res {
+        Ok(res) => Ok(negate_if(res, is_neg)),
+        Err(overflow) if is_neg => Err(FiniteOverflow {
+            sign: overflow.sign.reverse(),
+        }),
+        Err(overflow) => Err(overflow),
+    }
+}
+
+/// Computes arctangent of number in range `[-1, 1]` in radians.
+///
+/// Refines `f64` approximation by Newton's iterations
+/// `y = y - (sin(y) - num * cos(y)) * cos(y)`, each of them doubles correct digits.
+fn reduced_atan<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let mut res = Finite::from_approx(num.approx().atan());
+    let mut correct_digits = approx_correct_digits::<RADIX>();
+
+    while correct_digits < prec {
+        let (sin, cos) = (
+            reduced_sin(res.clone(), prec)?,
+            reduced_cos(res.clone(), prec)?,
+        );
+
+        let diff = finite_mul(num.clone(), cos.clone(), prec)?;
+        let diff = finite_add(sin, diff.reverse_sign(), prec)?;
+        let correction = finite_mul(diff, cos, prec)?;
+
+        res = finite_add(res, correction.reverse_sign(), prec)?;
+        correct_digits *= 2;
+    }
+
+    Ok(res)
+}
+
+/// Computes arctangent in radians.
+///
+/// Arctangent of number greater than one by modulus is computed
+/// as `±π/2 - atan(1 / num)`.
+pub fn finite_atan<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    if num.is_zero() {
+        return Ok(num);
+    }
+
+    if let Ordering::Less | Ordering::Equal = unsigned_finite_cmp(&num, &Finite::one()) {
+        return reduced_atan(num, prec);
+    }
+
+    let half_pi = finite_div(finite_pi(prec)?, Finite::from(2), prec)?.set_sign_of(&num);
+    let atan = reduced_atan(finite_div(Finite::one(), num, prec)?, prec)?;
+
+    finite_add(half_pi, atan.reverse_sign(), prec)
+}
+
+/// Computes arcsine of number in range `[-1, 1]` in radians
+/// as `atan(num / sqrt(1 - num^2))`.
+pub fn finite_asin<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let num_sq = finite_mul(num.clone(), num.clone(), prec)?;
+    let cos = finite_sqrt(
+        finite_add(Finite::one(), num_sq.reverse_sign(), prec)?,
+        prec,
+    )?;
+
+    if cos.is_zero() {
+        return Ok(finite_div(finite_pi(prec)?, Finite::from(2), prec)?.set_sign_of(&num));
+    }
+
+    finite_atan(finite_div(num, cos, prec)?, prec)
+}
+
+/// Computes arccosine of number in range `[-1, 1]` in radians as `π/2 - asin(num)`.
+///
+/// Arccosine of number above one half is small, then it is computed
+/// as `2 * asin(sqrt((1 - num) / 2))`, as digits cancel in the difference.
+pub fn finite_acos<const RADIX: u32, const PREC: isize>(
+    num: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let exact_prec = prec.max(2 - num.lsd_pos());
+    let diff = finite_add(Finite::one(), num.clone().reverse_sign(), exact_prec)?;
+    let half = finite_div(Finite::one(), Finite::from(2), prec)?;
+
+    if !diff.is_neg() && unsigned_finite_cmp(&diff, &half) == Ordering::Less {
+        let sin_half = finite_sqrt(finite_div(diff, Finite::from(2), prec)?, prec)?;
+        let asin = finite_asin(sin_half, prec)?;
+
+        return finite_add(asin.clone(), asin, prec);
+    }
+
+    let half_pi = finite_div(finite_pi(prec)?, Finite::from(2), prec)?;
+    finite_add(half_pi, finite_asin(num, prec)?.reverse_sign(), prec)
+}
+
+/// Computes angle between positive x axis and ray to point `(x, y)` in radians.
+///
+/// Signs of zero coordinates select the half-plane as in IEEE 754 `atan2`.
+pub fn finite_atan2<const RADIX: u32, const PREC: isize>(
+    y: Finite<RADIX, PREC>,
+    x: Finite<RADIX, PREC>,
+    prec: isize,
+) -> FiniteResult<RADIX, PREC> {
+    let half_pi = || -> FiniteResult<RADIX, PREC> {
+        Ok(finite_div(finite_pi(prec)?, Finite::from(2), prec)?.set_sign_of(&y))
+    };
+
+    if x.is_zero() && y.is_zero() {
+        return match x.is_neg() {
+            true => Ok(finite_pi(prec)?.set_sign_of(&y)),
+            false => Ok(y),
+        };
+    }
+
+    if x.is_zero() {
+        return half_pi();
+    }
+
+    let atan = match finite_div(y.clone(), x.clone(), prec) {
+        Ok(quotient) => finite_atan(quotient, prec)?,
+        Err(_) => return half_pi(),
+    };
+
+    if !x.is_neg() {
+        return Ok(atan);
+    }
+
+    finite_add(atan, finite_pi(prec)?.set_sign_of(&y), prec)
+}
@@ -1,11 +1,37 @@
+mod dyn_number;
+mod exp_range;
 mod finite;
 mod finite_cmp;
+mod finite_const;
+mod finite_math;
 mod finite_ops;
+mod finite_trig;
+mod limbs;
 mod number;
+mod number_checked;
+mod number_convert;
+mod number_format;
+mod number_math;
+mod number_spec;
+mod number_trig;
+mod rational;
+mod rounding;
 mod sign;
+mod status;
+mod total_order;
 
+pub use dyn_number::{DynNumber, TryFromNumberError};
+pub use exp_range::ExpRange;
 pub use finite::TryFromStrError;
+pub use finite_trig::AngleUnit;
 pub use number::Number;
+pub use number_checked::ArithmeticError;
+pub use number_format::{Notation, NumberFormat, TrailingZeros};
+pub use number_spec::NumberSpec;
+pub use rational::Rational;
+pub use rounding::RoundingMode;
+pub use status::Status;
+pub use total_order::TotalOrder;
 
 pub type Dec64 = number::Number<10, 64>;
 
@@ -0,0 +1,769 @@
+use std::cmp::Ordering;
+
+/// Operands shorter than this count of limbs are multiplied by schoolbook method.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// Quotients and divisors shorter than this count of limbs are computed by long division.
+const NEWTON_THRESHOLD: usize = 128;
+
+/// Returns count of radix digits packed into one limb.
+///
+/// Limbs are not greater than `2^31`, so product of two limbs with carries fits `u64`.
+const fn limb_digits(radix: u32) -> u32 {
+    let mut digits = 1;
+    let mut base = radix as u64;
+
+    while radix >= 2 && base * radix as u64 <= 1 << 31 {
+        base *= radix as u64;
+        digits += 1;
+    }
+
+    digits
+}
+
+/// Packing of radix digits into limbs.
+struct Limb<const RADIX: u32>;
+
+impl<const RADIX: u32> Limb<RADIX> {
+    const DIGITS: u32 = limb_digits(RADIX);
+    const BASE: u64 = (RADIX as u64).pow(Self::DIGITS);
+}
+
+/// Drops the most significant zero limbs.
+fn trim(mut limbs: Vec<u32>) -> Vec<u32> {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+
+    limbs
+}
+
+/// Returns slice without the most significant zero limbs.
+fn trimmed(limbs: &[u32]) -> &[u32] {
+    let len = limbs
+        .iter()
+        .rposition(|limb| *limb != 0)
+        .map_or(0, |pos| pos + 1);
+    &limbs[..len]
+}
+
+/// Returns `RADIX^exp`, `exp` must be less than count of digits in limb.
+fn radix_pow<const RADIX: u32>(exp: usize) -> u32 {
+    RADIX.pow(exp as u32)
+}
+
+/// Packs digits, the most significant first, into limbs.
+pub fn limbs_from_digits<const RADIX: u32>(digits: &[u32]) -> Vec<u32> {
+    let limbs = digits
+        .rchunks(Limb::<RADIX>::DIGITS as usize)
+        .map(|chunk| chunk.iter().fold(0, |acc, digit| acc * RADIX + digit))
+        .collect();
+
+    trim(limbs)
+}
+
+/// Packs integer into limbs.
+pub fn limbs_from_u64<const RADIX: u32>(int: u64) -> Vec<u32> {
+    limbs_from_u128::<RADIX>(int as u128)
+}
+
+/// Packs integer into limbs.
+pub fn limbs_from_u128<const RADIX: u32>(mut int: u128) -> Vec<u32> {
+    let mut limbs = Vec::new();
+
+    while int != 0 {
+        limbs.push((int % Limb::<RADIX>::BASE as u128) as u32);
+        int /= Limb::<RADIX>::BASE as u128;
+    }
+
+    limbs
+}
+
+/// Returns count of digits, zero has no digits.
+pub fn limbs_digits_len<const RADIX: u32>(limbs: &[u32]) -> usize {
+    let Some(&(mut top)) = limbs.last() else {
+        return 0;
+    };
+
+    let mut top_len = 0;
+
+    while top != 0 {
+        top /= RADIX;
+        top_len += 1;
+    }
+
+    (limbs.len() - 1) * Limb::<RADIX>::DIGITS as usize + top_len
+}
+
+/// Returns digit at position counted from the least significant digit.
+pub fn limbs_digit<const RADIX: u32>(limbs: &[u32], pos: usize) -> u32 {
+    let digits = Limb::<RADIX>::DIGITS as usize;
+
+    limbs
+        .get(pos / digits)
+        .map_or(0, |limb| limb / radix_pow::<RADIX>(pos % digits) % RADIX)
+}
+
+/// Returns count of zero digits below the least significant non-zero digit.
+pub fn limbs_trailing_zeros<const RADIX: u32>(limbs: &[u32]) -> usize {
+    let zero_limbs = limbs.iter().take_while(|limb| **limb == 0).count();
+
+    let Some(&(mut lowest)) = limbs.get(zero_limbs) else {
+        return 0;
+    };
+
+    let mut zeros_len = zero_limbs * Limb::<RADIX>::DIGITS as usize;
+
+    while lowest % RADIX == 0 {
+        lowest /= RADIX;
+        zeros_len += 1;
+    }
+
+    zeros_len
+}
+
+/// Checks if number is odd.
+pub fn limbs_is_odd<const RADIX: u32>(limbs: &[u32]) -> bool {
+    match Limb::<RADIX>::BASE % 2 {
+        0 => limbs.first().is_some_and(|limb| limb % 2 == 1),
+        _ => limbs.iter().fold(0, |acc, limb| acc ^ (limb % 2)) == 1,
+    }
+}
+
+pub fn limbs_cmp(lhs: &[u32], rhs: &[u32]) -> Ordering {
+    let (lhs, rhs) = (trimmed(lhs), trimmed(rhs));
+
+    lhs.len()
+        .cmp(&rhs.len())
+        .then_with(|| lhs.iter().rev().cmp(rhs.iter().rev()))
+}
+
+pub fn limbs_add<const RADIX: u32>(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut sum = lhs.to_vec();
+    add_shifted::<RADIX>(&mut sum, rhs, 0);
+
+    trim(sum)
+}
+
+/// Subtracts numbers, `lhs` must not be less than `rhs`.
+pub fn limbs_sub<const RADIX: u32>(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut diff = lhs.to_vec();
+    sub_shifted::<RADIX>(&mut diff, rhs, 0);
+
+    trim(diff)
+}
+
+/// Adds `rhs * BASE^shift` to `acc`.
+fn add_shifted<const RADIX: u32>(acc: &mut Vec<u32>, rhs: &[u32], shift: usize) {
+    if acc.len() < rhs.len() + shift {
+        acc.resize(rhs.len() + shift, 0);
+    }
+
+    let mut carry = 0;
+    let mut pos = shift;
+
+    for limb in rhs {
+        let sum = acc[pos] as u64 + *limb as u64 + carry;
+
+        acc[pos] = (sum % Limb::<RADIX>::BASE) as u32;
+        carry = sum / Limb::<RADIX>::BASE;
+        pos += 1;
+    }
+
+    while carry != 0 {
+        if pos == acc.len() {
+            acc.push(0);
+        }
+
+        let sum = acc[pos] as u64 + carry;
+
+        acc[pos] = (sum % Limb::<RADIX>::BASE) as u32;
+        carry = sum / Limb::<RADIX>::BASE;
+        pos += 1;
+    }
+}
+
+/// Subtracts `rhs * BASE^shift` from `acc`, that must not be less than it.
+fn sub_shifted<const RADIX: u32>(acc: &mut [u32], rhs: &[u32], shift: usize) {
+    let mut borrowing = 0;
+    let mut pos = shift;
+
+    for limb in rhs {
+        let subtracted = *limb as u64 + borrowing;
+
+        (acc[pos], borrowing) = match acc[pos] as u64 >= subtracted {
+            true => ((acc[pos] as u64 - subtracted) as u32, 0),
+            false => (
+                (Limb::<RADIX>::BASE + acc[pos] as u64 - subtracted) as u32,
+                1,
+            ),
+        };
+        pos += 1;
+    }
+
+    while borrowing != 0 {
+        if pos == acc.len() {
+            panic!("reduced number is less than subtracted number");
+        }
+
+        (acc[pos], borrowing) = match acc[pos] {
+            0 => ((Limb::<RADIX>::BASE - 1) as u32, 1),
+            limb => (limb - 1, 0),
+        };
+        pos += 1;
+    }
+}
+
+pub fn limbs_mul_small<const RADIX: u32>(limbs: &[u32], factor: u32) -> Vec<u32> {
+    let mut product = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0;
+
+    for limb in limbs {
+        let mul = *limb as u64 * factor as u64 + carry;
+
+        product.push((mul % Limb::<RADIX>::BASE) as u32);
+        carry = mul / Limb::<RADIX>::BASE;
+    }
+
+    product.push(carry as u32);
+
+    trim(product)
+}
+
+/// Divides number by non-zero number less than limb base.
+pub fn limbs_div_rem_small<const RADIX: u32>(limbs: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let mut quotient = vec![0; limbs.len()];
+    let mut rem = 0;
+
+    for (pos, limb) in limbs.iter().enumerate().rev() {
+        let cur = rem * Limb::<RADIX>::BASE + *limb as u64;
+
+        quotient[pos] = (cur / divisor as u64) as u32;
+        rem = cur % divisor as u64;
+    }
+
+    (trim(quotient), rem as u32)
+}
+
+fn schoolbook_mul<const RADIX: u32>(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let mut product = vec![0; lhs.len() + rhs.len()];
+
+    for (lhs_pos, lhs_limb) in lhs.iter().enumerate() {
+        if *lhs_limb == 0 {
+            continue;
+        }
+
+        let mut carry = 0;
+
+        for (rhs_pos, rhs_limb) in rhs.iter().enumerate() {
+            let cur =
+                product[lhs_pos + rhs_pos] as u64 + *lhs_limb as u64 * *rhs_limb as u64 + carry;
+
+            product[lhs_pos + rhs_pos] = (cur % Limb::<RADIX>::BASE) as u32;
+            carry = cur / Limb::<RADIX>::BASE;
+        }
+
+        product[lhs_pos + rhs.len()] = carry as u32;
+    }
+
+    trim(product)
+}
+
+/// Multiplies numbers, uses Karatsuba's method for long operands.
+///
+/// Operands are split into halves `x = x1 * BASE^half + x0`, then
+/// `x * y = z2 * BASE^(2 * half) + z1 * BASE^half + z0`, where `z0 = x0 * y0`,
+/// `z2 = x1 * y1` and `z1 = (x0 + x1) * (y0 + y1) - z0 - z2`.
+pub fn limbs_mul<const RADIX: u32>(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let (lhs, rhs) = (trimmed(lhs), trimmed(rhs));
+
+    if lhs.len().min(rhs.len()) < KARATSUBA_THRESHOLD {
+        return schoolbook_mul::<RADIX>(lhs, rhs);
+    }
+
+    let half = lhs.len().max(rhs.len()) / 2;
+
+    // Unbalanced operands: only the longer one is split
+    if lhs.len() <= half || rhs.len() <= half {
+        let (long, short) = match lhs.len() >= rhs.len() {
+            true => (lhs, rhs),
+            false => (rhs, lhs),
+        };
+
+        let (long0, long1) = long.split_at(half);
+
+        let mut product = limbs_mul::<RADIX>(long0, short);
+        add_shifted::<RADIX>(&mut product, &limbs_mul::<RADIX>(long1, short), half);
+
+        return trim(product);
+    }
+
+    let (lhs0, lhs1) = lhs.split_at(half);
+    let (rhs0, rhs1) = rhs.split_at(half);
+
+    let z0 = limbs_mul::<RADIX>(lhs0, rhs0);
+    let z2 = limbs_mul::<RADIX>(lhs1, rhs1);
+
+    let mut z1 = limbs_mul::<RADIX>(
+        &limbs_add::<RADIX>(lhs0, lhs1),
+        &limbs_add::<RADIX>(rhs0, rhs1),
+    );
+    sub_shifted::<RADIX>(&mut z1, &z0, 0);
+    sub_shifted::<RADIX>(&mut z1, &z2, 0);
+
+    let mut product = z0;
+    add_shifted::<RADIX>(&mut product, trimmed(&z1), half);
+    add_shifted::<RADIX>(&mut product, &z2, 2 * half);
+
+    trim(product)
+}
+
+/// Divides numbers, returns quotient and remainder.
+///
+/// Long quotients of long divisors are computed through reciprocal of divisor,
+/// other ones by long division.
+pub fn limbs_div_rem<const RADIX: u32>(lhs: &[u32], rhs: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let (lhs, rhs) = (trimmed(lhs), trimmed(rhs));
+
+    debug_assert!(!rhs.is_empty(), "division by zero");
+
+    if limbs_cmp(lhs, rhs) == Ordering::Less {
+        return (Vec::new(), lhs.to_vec());
+    }
+
+    if rhs.len() == 1 {
+        let (quotient, rem) = limbs_div_rem_small::<RADIX>(lhs, rhs[0]);
+        return (quotient, trim(vec![rem]));
+    }
+
+    match (lhs.len() - rhs.len() + 1).min(rhs.len()) < NEWTON_THRESHOLD {
+        true => long_div_rem::<RADIX>(lhs, rhs),
+        false => newton_div_rem::<RADIX>(lhs, rhs),
+    }
+}
+
+/// Divides numbers by Knuth's long division, `lhs` must not be less than `rhs`,
+/// that must have two limbs at least.
+///
+/// Operands are scaled so that the most significant limb of divisor is at least half
+/// of limb base, then each quotient limb estimated by two leading limbs is off by two at most.
+fn long_div_rem<const RADIX: u32>(lhs: &[u32], rhs: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let base = Limb::<RADIX>::BASE;
+
+    let scale = (base / (rhs[rhs.len() - 1] as u64 + 1)) as u32;
+    let divisor = limbs_mul_small::<RADIX>(rhs, scale);
+
+    let mut rem = limbs_mul_small::<RADIX>(lhs, scale);
+    rem.resize(lhs.len() + 1, 0);
+
+    let len = divisor.len();
+    let (top, next) = (divisor[len - 1] as u64, divisor[len - 2] as u64);
+    let mut quotient = vec![0; lhs.len() - len + 1];
+
+    for pos in (0..quotient.len()).rev() {
+        let leading = rem[pos + len] as u64 * base + rem[pos + len - 1] as u64;
+        let (mut digit, mut digit_rem) = (leading / top, leading % top);
+
+        while digit >= base || digit * next > digit_rem * base + rem[pos + len - 2] as u64 {
+            digit -= 1;
+            digit_rem += top;
+
+            if digit_rem >= base {
+                break;
+            }
+        }
+
+        let product = limbs_mul_small::<RADIX>(&divisor, digit as u32);
+
+        if limbs_cmp(&rem[pos..], &product) == Ordering::Less {
+            digit -= 1;
+            add_shifted::<RADIX>(&mut rem, &divisor, pos);
+        }
+
+        sub_shifted::<RADIX>(&mut rem, &product, pos);
+        rem.truncate(pos + len);
+        quotient[pos] = digit as u32;
+    }
+
+    let (rem, _) = limbs_div_rem_small::<RADIX>(&rem, scale);
+
+    (trim(quotient), rem)
+}
+
+/// Divides numbers through reciprocal of divisor, `lhs` must not be less than `rhs`.
+///
+/// Both operands are cut or padded, so that divisor is two limbs longer than quotient,
+/// then quotient is taken from product of leading limbs of dividend and reciprocal
+/// and corrected to exact one.
+fn newton_div_rem<const RADIX: u32>(lhs: &[u32], rhs: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    let len = lhs.len() - rhs.len() + 3;
+
+    let (dividend, divisor) = match rhs.len() >= len {
+        true => {
+            let cut = rhs.len() - len;
+            (lhs[cut..].to_vec(), rhs[cut..].to_vec())
+        }
+        false => {
+            let pad = len - rhs.len();
+            (shl_limbs(lhs, pad), shl_limbs(rhs, pad))
+        }
+    };
+
+    // Lower limbs of dividend change quotient by less than one
+    let cut = len - 2;
+    let recip = reciprocal::<RADIX>(&divisor);
+    let quotient = shr_limbs(&limbs_mul::<RADIX>(&dividend[cut..], &recip), 2 * len - cut);
+
+    correct_quotient::<RADIX>(lhs, rhs, quotient)
+}
+
+/// Returns approximation of `BASE^(2 * len) / divisor` off by few units,
+/// where `len` is count of limbs in divisor.
+///
+/// Reciprocal `x` of leading limbs of divisor is computed recursively and refined
+/// by one step of Newton's iteration `x + x * (1 - divisor * x)`, which doubles
+/// count of correct limbs.
+fn reciprocal<const RADIX: u32>(divisor: &[u32]) -> Vec<u32> {
+    let divisor = trimmed(divisor);
+    let len = divisor.len();
+
+    if len < NEWTON_THRESHOLD {
+        let mut power = vec![0; 2 * len];
+        power.push(1);
+
+        return match len {
+            1 => limbs_div_rem_small::<RADIX>(&power, divisor[0]).0,
+            _ => long_div_rem::<RADIX>(&power, divisor).0,
+        };
+    }
+
+    // Two guard limbs keep the refined reciprocal off by few units only
+    let half = (len + 5) / 2;
+    let low = len - half;
+    let approx = reciprocal::<RADIX>(&divisor[low..]);
+
+    // Both product and error are scaled down by `BASE^low`
+    let product = limbs_mul::<RADIX>(divisor, &approx);
+    let mut power = vec![0; 2 * len - low];
+    power.push(1);
+
+    // Lower limbs of error change correction by less than one
+    let cut = half - 3;
+    let order = limbs_cmp(&product, &power);
+    let error = match order {
+        Ordering::Greater => limbs_sub::<RADIX>(&product, &power),
+        _ => limbs_sub::<RADIX>(&power, &product),
+    };
+    let correction = shr_limbs(
+        &limbs_mul::<RADIX>(&approx, &shr_limbs(&error, cut)),
+        2 * (len - low) - cut,
+    );
+
+    let approx = shl_limbs(&approx, low);
+
+    match order {
+        Ordering::Greater => limbs_sub::<RADIX>(&approx, &limbs_add::<RADIX>(&correction, &[1])),
+        _ => limbs_add::<RADIX>(&approx, &correction),
+    }
+}
+
+/// Adjusts approximate quotient to exact one, returns quotient and remainder.
+fn correct_quotient<const RADIX: u32>(
+    lhs: &[u32],
+    rhs: &[u32],
+    mut quotient: Vec<u32>,
+) -> (Vec<u32>, Vec<u32>) {
+    let mut product = limbs_mul::<RADIX>(&quotient, rhs);
+
+    while limbs_cmp(&product, lhs) == Ordering::Greater {
+        quotient = limbs_sub::<RADIX>(&quotient, &[1]);
+        product = limbs_sub::<RADIX>(&product, rhs);
+    }
+
+    let mut rem = limbs_sub::<RADIX>(lhs, &product);
+
+    while limbs_cmp(&rem, rhs) != Ordering::Less {
+        quotient = limbs_add::<RADIX>(&quotient, &[1]);
+        rem = limbs_sub::<RADIX>(&rem, rhs);
+    }
+
+    (quotient, rem)
+}
+
+/// Multiplies number by `BASE^shift`.
+fn shl_limbs(limbs: &[u32], shift: usize) -> Vec<u32> {
+    let mut shifted = vec![0; shift];
+    shifted.extend_from_slice(limbs);
+
+    shifted
+}
+
+/// Divides number by `BASE^shift` rounding down.
+fn shr_limbs(limbs: &[u32], shift: usize) -> Vec<u32> {
+    limbs.get(shift..).map_or(Vec::new(), <[u32]>::to_vec)
+}
+
+/// Multiplies number by `RADIX^shift`.
+pub fn limbs_shl_digits<const RADIX: u32>(limbs: &[u32], shift: usize) -> Vec<u32> {
+    let digits = Limb::<RADIX>::DIGITS as usize;
+
+    if limbs.is_empty() {
+        return Vec::new();
+    }
+
+    let mut shifted = vec![0; shift / digits];
+    shifted.extend(limbs_mul_small::<RADIX>(
+        limbs,
+        radix_pow::<RADIX>(shift % digits),
+    ));
+
+    shifted
+}
+
+/// Divides number by `RADIX^shift`, returns quotient and remainder.
+pub fn limbs_split_digits<const RADIX: u32>(limbs: &[u32], shift: usize) -> (Vec<u32>, Vec<u32>) {
+    let digits = Limb::<RADIX>::DIGITS as usize;
+    let low_len = shift / digits;
+
+    if low_len >= limbs.len() {
+        return (Vec::new(), trim(limbs.to_vec()));
+    }
+
+    let (low, high) = limbs.split_at(low_len);
+    let (quotient, high_rem) =
+        limbs_div_rem_small::<RADIX>(high, radix_pow::<RADIX>(shift % digits));
+
+    let mut rem = low.to_vec();
+    rem.push(high_rem);
+
+    (quotient, trim(rem))
+}
+
+/// Computes greatest common divisor by Euclid's algorithm, divisor of zero and zero is zero.
+pub fn limbs_gcd<const RADIX: u32>(lhs: &[u32], rhs: &[u32]) -> Vec<u32> {
+    let (mut lhs, mut rhs) = (trimmed(lhs).to_vec(), trimmed(rhs).to_vec());
+
+    while !rhs.is_empty() {
+        let (_, rem) = limbs_div_rem::<RADIX>(&lhs, &rhs);
+        (lhs, rhs) = (rhs, rem);
+    }
+
+    lhs
+}
+
+/// Raises number to power by repeated squaring.
+pub fn limbs_pow<const RADIX: u32>(limbs: &[u32], mut exp: u64) -> Vec<u32> {
+    let mut base = trimmed(limbs).to_vec();
+    let mut pow = vec![1];
+
+    while exp != 0 {
+        if exp % 2 == 1 {
+            pow = limbs_mul::<RADIX>(&pow, &base);
+        }
+
+        exp /= 2;
+
+        if exp != 0 {
+            base = limbs_mul::<RADIX>(&base, &base);
+        }
+    }
+
+    pow
+}
+
+/// Computes integer part of `n`-th root by Newton's method, `n` is positive.
+pub fn limbs_nth_root<const RADIX: u32>(limbs: &[u32], n: u64) -> Vec<u32> {
+    let len = limbs_digits_len::<RADIX>(limbs);
+
+    if len == 0 || n == 1 {
+        return trimmed(limbs).to_vec();
+    }
+
+    // Number less than `2^n` has root in range `[1, 2)`
+    if n as f64 >= len as f64 * (RADIX as f64).log2() {
+        return vec![1];
+    }
+
+    // Starts above the root, then iterations decrease to it
+    let mut root = limbs_shl_digits::<RADIX>(&[1], len.div_ceil(n as usize));
+
+    loop {
+        let (quotient, _) = limbs_div_rem::<RADIX>(limbs, &limbs_pow::<RADIX>(&root, n - 1));
+        let sum = limbs_add::<RADIX>(&limbs_mul_small::<RADIX>(&root, (n - 1) as u32), &quotient);
+        let (next, _) = limbs_div_rem_small::<RADIX>(&sum, n as u32);
+
+        if limbs_cmp(&next, &root) != Ordering::Less {
+            return root;
+        }
+
+        root = next;
+    }
+}
+
+/// Repacks number from limbs of `FROM` radix digits into limbs of `TO` radix digits.
+pub fn limbs_convert<const FROM: u32, const TO: u32>(limbs: &[u32]) -> Vec<u32> {
+    if FROM == TO {
+        return trimmed(limbs).to_vec();
+    }
+
+    trimmed(limbs).iter().rev().fold(Vec::new(), |acc, limb| {
+        let shifted = limbs_mul_small::<TO>(&acc, Limb::<FROM>::BASE as u32);
+        limbs_add::<TO>(&shifted, &limbs_from_u64::<TO>(*limb as u64))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Generates limbs of `RADIX` by xorshift, so that tests are reproducible.
+    struct Limbs<const RADIX: u32> {
+        state: u64,
+    }
+
+    impl<const RADIX: u32> Limbs<RADIX> {
+        fn new(seed: u64) -> Self {
+            Self { state: seed | 1 }
+        }
+
+        /// Returns number of `len` limbs whose most significant limb is not zero.
+        fn take(&mut self, len: usize) -> Vec<u32> {
+            let mut limbs = (0..len)
+                .map(|_| {
+                    self.state ^= self.state << 13;
+                    self.state ^= self.state >> 7;
+                    self.state ^= self.state << 17;
+                    (self.state % Limb::<RADIX>::BASE) as u32
+                })
+                .collect::<Vec<_>>();
+
+            if let Some(last) = limbs.last_mut() {
+                *last = (*last).max(1);
+            }
+
+            limbs
+        }
+    }
+
+    /// Returns number of `len` limbs equal to `BASE - 1`.
+    fn max_limbs<const RADIX: u32>(len: usize) -> Vec<u32> {
+        vec![(Limb::<RADIX>::BASE - 1) as u32; len]
+    }
+
+    fn check_mul<const RADIX: u32>(lhs: &[u32], rhs: &[u32]) {
+        assert_eq!(
+            limbs_mul::<RADIX>(lhs, rhs),
+            schoolbook_mul::<RADIX>(lhs, rhs),
+            "{} by {} limbs in radix {}",
+            lhs.len(),
+            rhs.len(),
+            RADIX
+        );
+    }
+
+    fn check_karatsuba<const RADIX: u32>() {
+        let mut limbs = Limbs::<RADIX>::new(RADIX as u64);
+        let t = KARATSUBA_THRESHOLD;
+
+        let lens = [
+            (t - 1, t - 1),
+            (t, t),
+            (t + 1, t + 1),
+            (t, t - 1),
+            (t + 1, t),
+            (2 * t, t),
+            (2 * t + 1, t),
+            (5 * t, t + 3),
+            (3 * t, 2 * t + 5),
+            (4 * t, 4 * t),
+        ];
+
+        for (lhs_len, rhs_len) in lens {
+            let (lhs, rhs) = (limbs.take(lhs_len), limbs.take(rhs_len));
+            check_mul::<RADIX>(&lhs, &rhs);
+            check_mul::<RADIX>(&rhs, &lhs);
+
+            // Carries run through all limbs
+            check_mul::<RADIX>(&max_limbs::<RADIX>(lhs_len), &max_limbs::<RADIX>(rhs_len));
+        }
+
+        // Zero halves and untrimmed leading zeros
+        let mut sparse = limbs.take(2 * t);
+        sparse[..t].fill(0);
+        check_mul::<RADIX>(&sparse, &limbs.take(2 * t));
+
+        let mut padded = limbs.take(t + 1);
+        padded.extend([0, 0, 0]);
+        check_mul::<RADIX>(&padded, &limbs.take(t + 4));
+    }
+
+    #[test]
+    fn karatsuba_matches_schoolbook() {
+        check_karatsuba::<10>();
+        check_karatsuba::<2>();
+        check_karatsuba::<7>();
+        check_karatsuba::<36>();
+    }
+
+    fn check_div_rem<const RADIX: u32>(lhs: &[u32], rhs: &[u32]) {
+        let (quotient, rem) = limbs_div_rem::<RADIX>(lhs, rhs);
+        let name = format!("{} by {} limbs in radix {}", lhs.len(), rhs.len(), RADIX);
+
+        assert_eq!(limbs_cmp(&rem, rhs), Ordering::Less, "{}", name);
+        assert_eq!(
+            limbs_add::<RADIX>(&limbs_mul::<RADIX>(&quotient, rhs), &rem),
+            trimmed(lhs),
+            "{}",
+            name
+        );
+        assert_eq!((quotient, rem), long_div_rem::<RADIX>(lhs, rhs), "{}", name);
+    }
+
+    fn check_newton<const RADIX: u32>() {
+        let mut limbs = Limbs::<RADIX>::new(RADIX as u64 + 1);
+        let t = NEWTON_THRESHOLD;
+
+        // Lengths of divisor and quotient
+        let lens = [
+            (t - 1, t - 1),
+            (t, t - 1),
+            (t - 1, t),
+            (t, t),
+            (t + 1, t + 1),
+            (t, 2 * t + 3),
+            (2 * t + 3, t),
+            (t + 7, 2 * t),
+        ];
+
+        for (rhs_len, quotient_len) in lens {
+            let rhs = limbs.take(rhs_len);
+            let lhs = limbs.take(rhs_len + quotient_len - 1);
+            check_div_rem::<RADIX>(&lhs, &rhs);
+
+            // Quotient limbs are all `BASE - 1`, and remainder is the largest one
+            let lhs = limbs_sub::<RADIX>(
+                &limbs_mul::<RADIX>(&max_limbs::<RADIX>(quotient_len + 1), &rhs),
+                &[1],
+            );
+            check_div_rem::<RADIX>(&lhs, &rhs);
+        }
+
+        // Powers of limb base and powers of radix within limbs
+        for len in [t, t + 1, 2 * t] {
+            let mut power = vec![0; len];
+            power.push(1);
+
+            let lhs = limbs.take(2 * len + 5);
+            check_div_rem::<RADIX>(&lhs, &power);
+            check_div_rem::<RADIX>(&lhs, &limbs_shl_digits::<RADIX>(&[1], len * 3 + 1));
+            check_div_rem::<RADIX>(&limbs_mul::<RADIX>(&lhs, &power), &power);
+        }
+    }
+
+    #[test]
+    fn newton_division_is_exact() {
+        check_newton::<10>();
+        check_newton::<2>();
+        check_newton::<36>();
+    }
+}
@@ -1,10 +1,21 @@
 use std::cmp::{Ordering, PartialEq, PartialOrd};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::hash::{Hash, Hasher};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
 
 use crate::finite::{Finite, FiniteOverflow, TryFromStrError};
 use crate::finite_cmp::finite_cmp;
-use crate::finite_ops::{finite_add, finite_div, finite_mul, finite_neg};
+use crate::finite_ops::{
+    finite_add, finite_ceil, finite_div, finite_floor, finite_mul, finite_neg, finite_round,
+    finite_round_frac, finite_trunc, fit_to_prec,
+};
+use crate::limbs::limbs_convert;
+use crate::number_spec::prec_of;
+use crate::rounding::RoundingMode;
 use crate::sign::Sign;
+use crate::status::Status;
+use crate::total_order::TotalOrder;
 
 #[derive(Clone, Debug)]
 pub(crate) enum NumberKind<const RADIX: u32, const PREC: isize> {
@@ -28,7 +39,8 @@ impl<const RADIX: u32, const PREC: isize> TryFrom<&str> for Number<RADIX, PREC>
             Ok(f) => f as Finite<RADIX, PREC>,
         };
 
-        Ok(Self::finite(finite))
+        // Literal may be longer than precision
+        Ok(fit_to_prec(finite, Self::prec()).overflow_to_inf())
     }
 }
 
@@ -40,31 +52,64 @@ impl<const RADIX: u32, const PREC: isize> TryFrom<String> for Number<RADIX, PREC
     }
 }
 
-impl<const RADIX: u32, const PREC: isize> ToString for Number<RADIX, PREC> {
-    fn to_string(&self) -> String {
-        match &self.kind {
-            NumberKind::NaN => "NaN".to_string(),
-            NumberKind::NegInf => "-inf".to_string(),
-            NumberKind::Inf => "inf".to_string(),
-            NumberKind::Finite(finite) => finite.to_string(),
+impl<const RADIX: u32, const PREC: isize> Number<RADIX, PREC> {
+    /// Returns precision of arithmetic.
+    pub(crate) fn prec() -> isize {
+        prec_of::<PREC>()
+    }
+
+    /// Reinterprets number as number of other precision, radixes must be equal.
+    pub(crate) fn recast<const TO_RADIX: u32, const TO_PREC: isize>(
+        self,
+    ) -> Number<TO_RADIX, TO_PREC> {
+        let kind = match self.kind {
+            NumberKind::Finite(f) => NumberKind::Finite(f.recast()),
+            NumberKind::Inf => NumberKind::Inf,
+            NumberKind::NegInf => NumberKind::NegInf,
+            NumberKind::NaN => NumberKind::NaN,
+        };
+
+        Number { kind }
+    }
+
+    /// Converts number to radix `TO_RADIX`.
+    ///
+    /// Integers that fit precision are converted exactly,
+    /// other numbers are rounded to precision with the current rounding mode.
+    pub fn to_radix<const TO_RADIX: u32>(self) -> Number<TO_RADIX, PREC> {
+        if RADIX == TO_RADIX {
+            return self.recast();
+        }
+
+        match self.kind {
+            NumberKind::Finite(f) => {
+                let mantissa = limbs_convert::<RADIX, TO_RADIX>(f.limbs());
+                let converted = Finite::from_scaled_limbs(mantissa, RADIX, f.lsd_pos());
+
+                let converted = match f.is_neg() {
+                    true => converted.reverse_sign(),
+                    false => converted,
+                };
+
+                fit_to_prec(converted, Number::<TO_RADIX, PREC>::prec()).overflow_to_inf()
+            }
+            kind => Self { kind }.recast(),
         }
     }
-}
 
-impl<const RADIX: u32, const PREC: isize> Number<RADIX, PREC> {
-    fn inf() -> Self {
+    pub fn inf() -> Self {
         Self {
             kind: NumberKind::<RADIX, PREC>::Inf,
         }
     }
 
-    fn neg_inf() -> Self {
+    pub fn neg_inf() -> Self {
         Self {
             kind: NumberKind::<RADIX, PREC>::NegInf,
         }
     }
 
-    fn nan() -> Self {
+    pub fn nan() -> Self {
         Self {
             kind: NumberKind::<RADIX, PREC>::NaN,
         }
@@ -74,13 +119,60 @@ impl<const RADIX: u32, const PREC: isize> Number<RADIX, PREC> {
         matches!(self.kind, NumberKind::NaN)
     }
 
-    fn finite(finite: Finite<RADIX, PREC>) -> Self {
+    pub fn is_infinite(&self) -> bool {
+        matches!(self.kind, NumberKind::Inf | NumberKind::NegInf)
+    }
+
+    /// Checks if number has negative sign, including negative zero.
+    ///
+    /// NaN has no sign, so it is neither negative nor positive.
+    pub fn is_sign_negative(&self) -> bool {
+        matches!(self.sign(), Some(Sign::Neg))
+    }
+
+    /// Checks if number has positive sign, including positive zero.
+    pub fn is_sign_positive(&self) -> bool {
+        matches!(self.sign(), Some(Sign::Pos))
+    }
+
+    /// Compares numbers in total order
+    /// `-inf < negative numbers < -0 < 0 < positive numbers < inf < NaN`.
+    ///
+    /// Unlike `partial_cmp`, negative zero is less than positive one
+    /// and NaN is equal to itself, so numbers can be sorted with `sort_by`
+    /// or used as keys wrapped in `TotalOrder`.
+    pub fn total_cmp(&self, rhs: &Self) -> Ordering {
+        match (&self.kind, &rhs.kind) {
+            (NumberKind::NaN, NumberKind::NaN) => Ordering::Equal,
+            (NumberKind::NaN, _) => Ordering::Greater,
+            (_, NumberKind::NaN) => Ordering::Less,
+            (NumberKind::Inf, NumberKind::Inf) | (NumberKind::NegInf, NumberKind::NegInf) => {
+                Ordering::Equal
+            }
+            (NumberKind::NegInf, _) | (_, NumberKind::Inf) => Ordering::Less,
+            (NumberKind::Inf, _) | (_, NumberKind::NegInf) => Ordering::Greater,
+            (NumberKind::Finite(lhs), NumberKind::Finite(rhs))
+                if lhs.is_zero() && rhs.is_zero() =>
+            {
+                rhs.is_neg().cmp(&lhs.is_neg())
+            }
+            (NumberKind::Finite(lhs), NumberKind::Finite(rhs)) => finite_cmp(lhs, rhs),
+        }
+    }
+
+    /// Returns NaN of invalid operation, raises invalid flag.
+    pub(crate) fn invalid() -> Self {
+        Status::raise_invalid();
+        Self::nan()
+    }
+
+    pub(crate) fn finite(finite: Finite<RADIX, PREC>) -> Self {
         Self {
             kind: NumberKind::<RADIX, PREC>::Finite(finite),
         }
     }
 
-    fn sign(&self) -> Option<Sign> {
+    pub(crate) fn sign(&self) -> Option<Sign> {
         let sign = match &self.kind {
             NumberKind::Inf => Sign::Pos,
             NumberKind::NegInf => Sign::Neg,
@@ -92,7 +184,15 @@ impl<const RADIX: u32, const PREC: isize> Number<RADIX, PREC> {
         Some(sign)
     }
 
-    fn set_sign(self, sign: Sign) -> Self {
+    /// Returns sign of product or quotient of numbers, it is `None` if any of them is NaN.
+    fn product_sign(&self, rhs: &Self) -> Option<Sign> {
+        match (self.sign()?, rhs.sign()?) {
+            (Sign::Pos, Sign::Pos) | (Sign::Neg, Sign::Neg) => Some(Sign::Pos),
+            (Sign::Pos, Sign::Neg) | (Sign::Neg, Sign::Pos) => Some(Sign::Neg),
+        }
+    }
+
+    pub(crate) fn set_sign(self, sign: Sign) -> Self {
         match self.kind {
             NumberKind::Inf | NumberKind::NegInf => match sign {
                 Sign::Pos => Self::inf(),
@@ -110,9 +210,68 @@ impl<const RADIX: u32, const PREC: isize> Number<RADIX, PREC> {
     pub fn one() -> Self {
         Self::finite(Finite::one())
     }
+
+    pub fn abs(self) -> Self {
+        match self.kind {
+            NumberKind::NaN => Self::nan(),
+            NumberKind::Inf | NumberKind::NegInf => Self::inf(),
+            NumberKind::Finite(f) => Self::finite(f.set_sign(&Sign::Pos)),
+        }
+    }
+
+    /// Returns integer part of number.
+    pub fn trunc(self) -> Self {
+        match self.kind {
+            NumberKind::Finite(f) => Self::finite(finite_trunc(f)),
+            kind => Self { kind },
+        }
+    }
+
+    /// Returns the largest integer less than or equal to number.
+    pub fn floor(self) -> Self {
+        match self.kind {
+            NumberKind::Finite(f) => {
+                Status::isolated(|| finite_floor(f, Self::prec())).overflow_to_inf()
+            }
+            kind => Self { kind },
+        }
+    }
+
+    /// Returns the smallest integer greater than or equal to number.
+    pub fn ceil(self) -> Self {
+        match self.kind {
+            NumberKind::Finite(f) => {
+                Status::isolated(|| finite_ceil(f, Self::prec())).overflow_to_inf()
+            }
+            kind => Self { kind },
+        }
+    }
+
+    /// Returns the nearest integer to number, rounds half away from zero.
+    pub fn round(self) -> Self {
+        match self.kind {
+            NumberKind::Finite(f) => {
+                Status::isolated(|| finite_round(f, Self::prec())).overflow_to_inf()
+            }
+            kind => Self { kind },
+        }
+    }
+
+    /// Rounds number to `digits` fractional digits with given rounding mode.
+    ///
+    /// Negative `digits` round integer part to multiple of `RADIX^-digits`.
+    pub fn round_to(self, digits: isize, mode: RoundingMode) -> Self {
+        match self.kind {
+            NumberKind::Finite(f) => {
+                Status::isolated(|| finite_round_frac(f, digits, mode, Self::prec()))
+                    .overflow_to_inf()
+            }
+            kind => Self { kind },
+        }
+    }
 }
 
-trait OverflowToInf<const RADIX: u32, const PREC: isize> {
+pub(crate) trait OverflowToInf<const RADIX: u32, const PREC: isize> {
     fn overflow_to_inf(self) -> Number<RADIX, PREC>;
 }
 
@@ -128,40 +287,77 @@ impl<const RADIX: u32, const PREC: isize> OverflowToInf<RADIX, PREC>
     fn overflow_to_inf(self) -> Number<RADIX, PREC> {
         match self {
             Ok(finite) => Number::finite(finite),
-            Err(overflow) => match overflow.sign {
-                Sign::Pos => Number::inf(),
-                Sign::Neg => Number::neg_inf(),
-            },
+            Err(overflow) => {
+                Status::raise_overflow();
+
+                match overflow.sign {
+                    Sign::Pos => Number::inf(),
+                    Sign::Neg => Number::neg_inf(),
+                }
+            }
         }
     }
 }
 
 impl<const RADIX: u32, const PREC: isize> PartialEq for Number<RADIX, PREC> {
+    /// Zeros are equal regardless of sign, NaN is not equal to any number including itself.
     fn eq(&self, rhs: &Self) -> bool {
         matches!(self.partial_cmp(rhs), Some(Ordering::Equal))
     }
+}
 
-    fn ne(&self, rhs: &Self) -> bool {
-        matches!(
-            self.partial_cmp(rhs),
-            Some(Ordering::Less | Ordering::Greater)
-        )
+/// Equal numbers have equal hashes, in particular zeros of both signs.
+///
+/// Equality is not reflexive for NaN, so keys of maps are wrapped in `TotalOrder`.
+impl<const RADIX: u32, const PREC: isize> Hash for Number<RADIX, PREC> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match &self.kind {
+            NumberKind::Finite(f) => f.hash(state),
+            NumberKind::Inf => 1u8.hash(state),
+            NumberKind::NegInf => 2u8.hash(state),
+            NumberKind::NaN => 3u8.hash(state),
+        }
     }
 }
 
 impl<const RADIX: u32, const PREC: isize> PartialOrd for Number<RADIX, PREC> {
     fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
-        let ord = match (&self.kind, &rhs.kind) {
-            (NumberKind::NaN, _) | (_, NumberKind::NaN) => return None,
-            (NumberKind::Inf, NumberKind::Inf) | (NumberKind::NegInf, NumberKind::NegInf) => {
-                Ordering::Equal
+        match (&self.kind, &rhs.kind) {
+            (NumberKind::NaN, _) | (_, NumberKind::NaN) => None,
+            (NumberKind::Finite(lhs), NumberKind::Finite(rhs))
+                if lhs.is_zero() && rhs.is_zero() =>
+            {
+                Some(Ordering::Equal)
             }
-            (NumberKind::NegInf, _) | (_, NumberKind::Inf) => Ordering::Less,
-            (NumberKind::Inf, _) | (_, NumberKind::NegInf) => Ordering::Greater,
-            (NumberKind::Finite(lhs), NumberKind::Finite(rhs)) => finite_cmp(lhs, rhs),
-        };
+            _ => Some(self.total_cmp(rhs)),
+        }
+    }
+}
 
-        Some(ord)
+impl<const RADIX: u32, const PREC: isize> PartialEq for TotalOrder<Number<RADIX, PREC>> {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.cmp(rhs) == Ordering::Equal
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> Eq for TotalOrder<Number<RADIX, PREC>> {}
+
+/// Numbers equal in total order are equal by `==` as well, so they have equal hashes.
+impl<const RADIX: u32, const PREC: isize> Hash for TotalOrder<Number<RADIX, PREC>> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> PartialOrd for TotalOrder<Number<RADIX, PREC>> {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        Some(self.cmp(rhs))
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> Ord for TotalOrder<Number<RADIX, PREC>> {
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.0.total_cmp(&rhs.0)
     }
 }
 
@@ -183,16 +379,16 @@ impl<const RADIX: u32, const PREC: isize> Add for Number<RADIX, PREC> {
 
     fn add(self, rhs: Self) -> Self::Output {
         match (self.kind, rhs.kind) {
-            (NumberKind::Inf, NumberKind::NegInf)
-            | (NumberKind::NegInf, NumberKind::Inf)
-            | (NumberKind::NaN, _)
-            | (_, NumberKind::NaN) => Self::nan(),
+            (NumberKind::NaN, _) | (_, NumberKind::NaN) => Self::nan(),
+            (NumberKind::Inf, NumberKind::NegInf) | (NumberKind::NegInf, NumberKind::Inf) => {
+                Self::invalid()
+            }
 
             (NumberKind::Inf, _) | (_, NumberKind::Inf) => Self::inf(),
             (NumberKind::NegInf, _) | (_, NumberKind::NegInf) => Self::neg_inf(),
 
             (NumberKind::Finite(lhs_finite), NumberKind::Finite(rhs_finite)) => {
-                finite_add(lhs_finite, rhs_finite).overflow_to_inf()
+                finite_add(lhs_finite, rhs_finite, Self::prec()).overflow_to_inf()
             }
         }
     }
@@ -221,20 +417,24 @@ impl<const RADIX: u32, const PREC: isize> SubAssign for Number<RADIX, PREC> {
 impl<const RADIX: u32, const PREC: isize> Mul for Number<RADIX, PREC> {
     type Output = Self;
 
+    /// Product of infinity and zero is NaN.
     fn mul(self, rhs: Self) -> Self::Output {
-        let (lhs_sign, rhs_sign) = (self.sign(), rhs.sign());
-
-        let res_sign = match (lhs_sign.unwrap(), rhs_sign.unwrap()) {
-            (Sign::Pos, Sign::Pos) | (Sign::Neg, Sign::Neg) => Sign::Pos,
-            (Sign::Pos, Sign::Neg) | (Sign::Neg, Sign::Pos) => Sign::Neg,
+        let Some(res_sign) = self.product_sign(&rhs) else {
+            return Self::nan();
         };
 
         match (self.kind, rhs.kind) {
-            (NumberKind::NaN, _) | (_, NumberKind::NaN) => return Self::nan(),
+            (NumberKind::NaN, _) | (_, NumberKind::NaN) => Self::nan(),
+            (NumberKind::Inf | NumberKind::NegInf, NumberKind::Finite(f))
+            | (NumberKind::Finite(f), NumberKind::Inf | NumberKind::NegInf)
+                if f.is_zero() =>
+            {
+                Self::invalid()
+            }
             (NumberKind::Inf | NumberKind::NegInf, _)
             | (_, NumberKind::Inf | NumberKind::NegInf) => Self::inf().set_sign(res_sign),
             (NumberKind::Finite(lhs_finite), NumberKind::Finite(rhs_finite)) => {
-                finite_mul(lhs_finite, rhs_finite).overflow_to_inf()
+                finite_mul(lhs_finite, rhs_finite, Self::prec()).overflow_to_inf()
             }
         }
     }
@@ -249,19 +449,26 @@ impl<const RADIX: u32, const PREC: isize> MulAssign for Number<RADIX, PREC> {
 impl<const RADIX: u32, const PREC: isize> Div for Number<RADIX, PREC> {
     type Output = Self;
 
+    /// Division of zero by zero and infinity by infinity is NaN,
+    /// division of other numbers by zero is infinity with sign of quotient.
     fn div(self, rhs: Self) -> Self::Output {
-        let (lhs_sign, rhs_sign) = (self.sign(), rhs.sign());
-
-        let res_sign = match (lhs_sign.unwrap(), rhs_sign.unwrap()) {
-            (Sign::Pos, Sign::Pos) | (Sign::Neg, Sign::Neg) => Sign::Pos,
-            (Sign::Pos, Sign::Neg) | (Sign::Neg, Sign::Pos) => Sign::Neg,
+        let Some(res_sign) = self.product_sign(&rhs) else {
+            return Self::nan();
         };
 
         match (self.kind, rhs.kind) {
-            (NumberKind::NaN, _)
-            | (_, NumberKind::NaN)
-            | (NumberKind::Inf | NumberKind::NegInf, NumberKind::Inf | NumberKind::NegInf) => {
-                Self::nan()
+            (NumberKind::Finite(lhs_finite), NumberKind::Finite(rhs_finite))
+                if lhs_finite.is_zero() && rhs_finite.is_zero() =>
+            {
+                Self::invalid()
+            }
+            (NumberKind::Finite(_), NumberKind::Finite(rhs_finite)) if rhs_finite.is_zero() => {
+                Status::raise_div_by_zero();
+                Self::inf().set_sign(res_sign)
+            }
+            (NumberKind::NaN, _) | (_, NumberKind::NaN) => Self::nan(),
+            (NumberKind::Inf | NumberKind::NegInf, NumberKind::Inf | NumberKind::NegInf) => {
+                Self::invalid()
             }
             (NumberKind::Finite(_), NumberKind::Inf | NumberKind::NegInf) => {
                 Self::zero().set_sign(res_sign)
@@ -270,7 +477,7 @@ impl<const RADIX: u32, const PREC: isize> Div for Number<RADIX, PREC> {
                 Self::inf().set_sign(res_sign)
             }
             (NumberKind::Finite(lhs_finite), NumberKind::Finite(rhs_finite)) => {
-                finite_div(lhs_finite, rhs_finite).overflow_to_inf()
+                finite_div(lhs_finite, rhs_finite, Self::prec()).overflow_to_inf()
             }
         }
     }
@@ -281,3 +488,17 @@ impl<const RADIX: u32, const PREC: isize> DivAssign for Number<RADIX, PREC> {
         *self = self.clone().div(rhs);
     }
 }
+
+impl<const RADIX: u32, const PREC: isize> Rem for Number<RADIX, PREC> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.rem_by(rhs, false)
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> RemAssign for Number<RADIX, PREC> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.clone().rem(rhs);
+    }
+}
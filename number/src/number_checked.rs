@@ -0,0 +1,82 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use crate::number::Number;
+use crate::status::Status;
+
+/// Error of checked arithmetic.
+///
+/// Rounding is not an error, results may be inexact.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArithmeticError {
+    /// Result is too large by modulus to be finite.
+    Overflow,
+    /// Result is too small by modulus and lost precision.
+    Underflow,
+    /// Finite number is divided by zero.
+    DivisionByZero,
+    /// Operation has no meaningful result, e.g. `0/0` or `inf - inf`.
+    Invalid,
+    /// Result is rounded, only conversions that must be exact fail with it.
+    Inexact,
+}
+
+impl Display for ArithmeticError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let msg = match self {
+            Self::Overflow => "overflow",
+            Self::Underflow => "underflow",
+            Self::DivisionByZero => "division by zero",
+            Self::Invalid => "invalid operation",
+            Self::Inexact => "inexact result",
+        };
+
+        write!(f, "{}", msg)
+    }
+}
+
+impl ArithmeticError {
+    /// Returns the most severe error flagged in status.
+    pub(crate) fn from_status(status: Status) -> Option<Self> {
+        match status {
+            _ if status.invalid() => Some(Self::Invalid),
+            _ if status.div_by_zero() => Some(Self::DivisionByZero),
+            _ if status.overflow() => Some(Self::Overflow),
+            _ if status.underflow() => Some(Self::Underflow),
+            _ => None,
+        }
+    }
+
+    /// Runs computation and turns flags it raised into error.
+    pub(crate) fn check<T>(f: impl FnOnce() -> T) -> Result<T, Self> {
+        let (res, status) = Status::capture(f);
+
+        match Self::from_status(status) {
+            Some(err) => Err(err),
+            None => Ok(res),
+        }
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> Number<RADIX, PREC> {
+    /// Adds numbers, fails instead of returning infinity or NaN for finite or infinite operands.
+    ///
+    /// NaN operands are not errors, they give NaN.
+    pub fn checked_add(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        ArithmeticError::check(|| self + rhs)
+    }
+
+    /// Subtracts numbers, fails as `checked_add` does.
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        ArithmeticError::check(|| self - rhs)
+    }
+
+    /// Multiplies numbers, fails as `checked_add` does.
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        ArithmeticError::check(|| self * rhs)
+    }
+
+    /// Divides numbers, fails as `checked_add` does and on division by zero.
+    pub fn checked_div(self, rhs: Self) -> Result<Self, ArithmeticError> {
+        ArithmeticError::check(|| self / rhs)
+    }
+}
@@ -0,0 +1,298 @@
+use std::iter::{Product, Sum};
+use std::str::FromStr;
+
+use crate::finite::{Finite, TryFromStrError};
+use crate::finite_ops::{finite_round_frac, fit_to_prec};
+use crate::limbs::{
+    limbs_convert, limbs_digit, limbs_digits_len, limbs_div_rem, limbs_from_u64, limbs_mul,
+    limbs_pow, limbs_shl_digits, limbs_split_digits,
+};
+use crate::number::{Number, NumberKind, OverflowToInf};
+use crate::number_checked::ArithmeticError;
+use crate::rounding::RoundingMode;
+use crate::status::Status;
+
+/// Implements conversions from primitive signed integers.
+macro_rules! impl_try_from_int {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl<const RADIX: u32, const PREC: isize> TryFrom<$int> for Number<RADIX, PREC> {
+                type Error = ArithmeticError;
+
+                /// Converts integer exactly, fails if it does not fit precision.
+                fn try_from(int: $int) -> Result<Self, Self::Error> {
+                    let finite = Finite::from_u128(int.unsigned_abs() as u128);
+
+                    let finite = match int < 0 {
+                        true => finite.reverse_sign(),
+                        false => finite,
+                    };
+
+                    Self::from_int_exact(finite)
+                }
+            }
+        )*
+    };
+}
+
+/// Implements conversions from primitive unsigned integers.
+macro_rules! impl_try_from_uint {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl<const RADIX: u32, const PREC: isize> TryFrom<$int> for Number<RADIX, PREC> {
+                type Error = ArithmeticError;
+
+                /// Converts integer exactly, fails if it does not fit precision.
+                fn try_from(int: $int) -> Result<Self, Self::Error> {
+                    Self::from_int_exact(Finite::from_u128(int as u128))
+                }
+            }
+        )*
+    };
+}
+
+/// Implements conversions to primitive integers.
+macro_rules! impl_try_into_int {
+    ($($int:ty),* $(,)?) => {
+        $(
+            impl<const RADIX: u32, const PREC: isize> TryFrom<Number<RADIX, PREC>> for $int {
+                type Error = ArithmeticError;
+
+                /// Rounds number to integer with the current rounding mode.
+                ///
+                /// Fails with overflow if integer does not fit the type,
+                /// NaN is invalid.
+                fn try_from(num: Number<RADIX, PREC>) -> Result<Self, Self::Error> {
+                    let (modulus, is_neg) = num.to_int_parts()?;
+
+                    let int = match is_neg {
+                        true => 0i128
+                            .checked_sub_unsigned(modulus)
+                            .and_then(|int| <$int>::try_from(int).ok()),
+                        false => <$int>::try_from(modulus).ok(),
+                    };
+
+                    int.ok_or(ArithmeticError::Overflow)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_int!(i8, i16, i32, i64, i128, isize);
+impl_try_from_uint!(u8, u16, u32, u64, u128, usize);
+impl_try_into_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<const RADIX: u32, const PREC: isize> TryFrom<f64> for Number<RADIX, PREC> {
+    type Error = ArithmeticError;
+
+    /// Converts the shortest decimal that round-trips to `val`, e.g. `0.1` rather than
+    /// `0.1000000000000000055511151231257827`.
+    ///
+    /// Decimals that do not fit precision are rounded with the current rounding mode.
+    /// Fails if the decimal is out of the current exponent range.
+    fn try_from(val: f64) -> Result<Self, Self::Error> {
+        if val.is_nan() {
+            return Ok(Self::nan());
+        }
+
+        if val.is_infinite() {
+            return Ok(match val > 0f64 {
+                true => Self::inf(),
+                false => Self::neg_inf(),
+            });
+        }
+
+        // Shortest round-trip digits are formatted as `d.ddde-x`
+        let formatted = format!("{:e}", val.abs());
+        let (significand, exp) = formatted.split_once('e').expect("exponent is formatted");
+        let frac_len = significand
+            .split_once('.')
+            .map_or(0, |(_, frac)| frac.len());
+        let mantissa = significand
+            .replace('.', "")
+            .parse::<u64>()
+            .expect("digits fit u64");
+        let exp = exp.parse::<isize>().expect("exponent is integer") - frac_len as isize;
+
+        let finite = Finite::from_scaled_limbs(limbs_from_u64::<RADIX>(mantissa), 10, exp);
+
+        let finite = match val.is_sign_negative() {
+            true => finite.reverse_sign(),
+            false => finite,
+        };
+
+        ArithmeticError::check(|| fit_to_prec(finite, Self::prec()).overflow_to_inf())
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> FromStr for Number<RADIX, PREC> {
+    type Err = TryFromStrError<RADIX, PREC>;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Self::try_from(src)
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> Sum for Number<RADIX, PREC> {
+    /// Sum of no numbers is zero.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, num| acc + num)
+    }
+}
+
+impl<'a, const RADIX: u32, const PREC: isize> Sum<&'a Self> for Number<RADIX, PREC> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.cloned().sum()
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> Product for Number<RADIX, PREC> {
+    /// Product of no numbers is one.
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, num| acc * num)
+    }
+}
+
+impl<'a, const RADIX: u32, const PREC: isize> Product<&'a Self> for Number<RADIX, PREC> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.cloned().product()
+    }
+}
+
+/// Multiplies `val` by `2^exp`, exactly unless the result is out of `f64` range.
+fn mul_pow2(val: f64, exp: i32) -> f64 {
+    // Single power of two may be out of range even if the result is not
+    let half = exp / 2;
+    val * 2f64.powi(half) * 2f64.powi(exp - half)
+}
+
+/// Rounds finite number to the nearest `f64`, ties to even.
+fn finite_to_f64<const RADIX: u32, const PREC: isize>(num: &Finite<RADIX, PREC>) -> f64 {
+    let sign = if num.is_neg() { -1f64 } else { 1f64 };
+
+    if num.is_zero() {
+        return sign * 0f64;
+    }
+
+    // Numbers far out of range are not expanded to binary
+    let log2 = num.approx_ln() / std::f64::consts::LN_2;
+
+    if log2 > 1100f64 {
+        return sign * f64::INFINITY;
+    }
+
+    if log2 < -1100f64 {
+        return sign * 0f64;
+    }
+
+    // Value is exactly `numer / denom`, both in binary digits
+    let mantissa = limbs_convert::<RADIX, 2>(num.limbs());
+    let scale = limbs_pow::<2>(
+        &limbs_from_u64::<2>(RADIX as u64),
+        num.lsd_pos().unsigned_abs() as u64,
+    );
+
+    let (numer, denom) = match num.lsd_pos() >= 0 {
+        true => (limbs_mul::<2>(&mantissa, &scale), vec![1]),
+        false => (mantissa, scale),
+    };
+
+    // Quotient `numer * 2^shift / denom` has 64 or 65 bits
+    let mut shift =
+        64 - limbs_digits_len::<2>(&numer) as isize + limbs_digits_len::<2>(&denom) as isize;
+
+    let (numer, denom) = match shift >= 0 {
+        true => (limbs_shl_digits::<2>(&numer, shift as usize), denom),
+        false => (numer, limbs_shl_digits::<2>(&denom, shift.unsigned_abs())),
+    };
+
+    let (mut quotient, rem) = limbs_div_rem::<2>(&numer, &denom);
+    let mut sticky = !rem.is_empty();
+
+    if limbs_digits_len::<2>(&quotient) > 64 {
+        let (high, low) = limbs_split_digits::<2>(&quotient, 1);
+        (quotient, sticky) = (high, sticky || !low.is_empty());
+        shift -= 1;
+    }
+
+    let bits = (0..64).rev().fold(0u64, |acc, pos| {
+        (acc << 1) | limbs_digit::<2>(&quotient, pos) as u64
+    });
+
+    // Position of the most significant bit
+    let msb_pos = 63 - shift;
+
+    if msb_pos >= f64::MIN_EXP as isize - 1 {
+        // Sticky bit is far below the rounding position, so the cast rounds correctly
+        return sign * mul_pow2((bits | sticky as u64) as f64, -shift as i32);
+    }
+
+    // Subnormal results keep bits down to `2^-1074`
+    let kept_len = msb_pos + 1075;
+
+    if kept_len < 0 {
+        return sign * 0f64;
+    }
+
+    let dropped_len = 64 - kept_len as u32;
+    let bits = bits as u128;
+    let (kept, dropped) = (bits >> dropped_len, bits & ((1 << dropped_len) - 1));
+    let half = 1 << (dropped_len - 1);
+
+    let kept = match dropped > half || dropped == half && (sticky || kept % 2 == 1) {
+        true => kept + 1,
+        false => kept,
+    };
+
+    sign * mul_pow2(kept as f64, -1074)
+}
+
+impl<const RADIX: u32, const PREC: isize> Number<RADIX, PREC> {
+    /// Converts number to the nearest `f64`, ties to even.
+    ///
+    /// Numbers out of `f64` range become infinities or zeros.
+    pub fn to_f64(&self) -> f64 {
+        match &self.kind {
+            NumberKind::Finite(f) => finite_to_f64(f),
+            NumberKind::Inf => f64::INFINITY,
+            NumberKind::NegInf => f64::NEG_INFINITY,
+            NumberKind::NaN => f64::NAN,
+        }
+    }
+
+    /// Converts integer to number, fails instead of rounding it.
+    ///
+    /// Failed conversion raises no flags.
+    fn from_int_exact(int: Finite<RADIX, PREC>) -> Result<Self, ArithmeticError> {
+        let (num, status) = Status::isolated(|| {
+            Status::capture(|| fit_to_prec(int, Self::prec()).overflow_to_inf())
+        });
+
+        match ArithmeticError::from_status(status) {
+            Some(err) => Err(err),
+            None if status.inexact() => Err(ArithmeticError::Inexact),
+            None => Ok(num),
+        }
+    }
+
+    /// Rounds number to integer with the current rounding mode,
+    /// returns its modulus and whether it is negative.
+    fn to_int_parts(&self) -> Result<(u128, bool), ArithmeticError> {
+        let int = match &self.kind {
+            NumberKind::Finite(f) => Status::isolated(|| {
+                finite_round_frac(f.clone(), 0, RoundingMode::current(), Self::prec()).ok()
+            }),
+            NumberKind::Inf | NumberKind::NegInf => return Err(ArithmeticError::Overflow),
+            NumberKind::NaN => return Err(ArithmeticError::Invalid),
+        };
+
+        match int {
+            Some(int) => match int.to_u128() {
+                Some(modulus) => Ok((modulus, int.is_neg())),
+                None => Err(ArithmeticError::Overflow),
+            },
+            None => Err(ArithmeticError::Overflow),
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use std::fmt::{Display, Formatter, LowerExp, Result as FmtResult, UpperExp};
+
+use crate::finite::{exp_marker_of, Finite};
+use crate::finite_ops::finite_round_frac;
+use crate::number::{Number, NumberKind};
+use crate::rounding::RoundingMode;
+use crate::sign::Sign;
+use crate::status::Status;
+
+/// Notation of formatted numbers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Notation {
+    /// Positional notation, or scientific one if integer part does not fit precision
+    /// or number has more than six leading fractional zeros.
+    #[default]
+    Auto,
+    /// Positional notation, e.g. `1234.5`.
+    Fixed,
+    /// One integer digit and exponent, e.g. `1.2345e3`.
+    Scientific,
+    /// One to three integer digits and exponent multiple of three, e.g. `1.2345e3`.
+    Engineering,
+}
+
+/// Policy of trailing fractional zeros of numbers rounded to given count of digits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TrailingZeros {
+    /// Pads fractional part with zeros to the count of digits, e.g. `1.50`.
+    #[default]
+    Keep,
+    /// Drops trailing fractional zeros and point if fractional part is zero, e.g. `1.5`.
+    Trim,
+}
+
+/// Settings of number formatting.
+///
+/// Default settings format numbers as `to_string` does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumberFormat {
+    notation: Notation,
+    frac_digits: Option<usize>,
+    trailing_zeros: TrailingZeros,
+    group_separator: Option<char>,
+    group_size: usize,
+    exp_marker: Option<char>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            notation: Notation::default(),
+            frac_digits: None,
+            trailing_zeros: TrailingZeros::default(),
+            group_separator: None,
+            group_size: 3,
+            exp_marker: None,
+        }
+    }
+}
+
+impl NumberFormat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    /// Rounds numbers to `frac_digits` fractional digits with the current rounding mode,
+    /// in scientific and engineering notations fractional digits of significand.
+    ///
+    /// Without the count numbers are formatted with all their digits.
+    pub fn with_frac_digits(mut self, frac_digits: usize) -> Self {
+        self.frac_digits = Some(frac_digits);
+        self
+    }
+
+    pub fn with_trailing_zeros(mut self, trailing_zeros: TrailingZeros) -> Self {
+        self.trailing_zeros = trailing_zeros;
+        self
+    }
+
+    /// Separates groups of integer digits with `separator`, e.g. `1,234,567`.
+    pub fn with_grouping(mut self, separator: char) -> Self {
+        self.group_separator = Some(separator);
+        self
+    }
+
+    /// Sets count of digits in group, zero disables grouping.
+    pub fn with_group_size(mut self, group_size: usize) -> Self {
+        self.group_size = group_size;
+        self
+    }
+
+    /// Sets symbol that separates exponent from significand.
+    ///
+    /// By default it is `e`, or `@` in radixes whose digits include `e`.
+    pub fn with_exp_marker(mut self, exp_marker: char) -> Self {
+        self.exp_marker = Some(exp_marker);
+        self
+    }
+
+    pub fn notation(&self) -> Notation {
+        self.notation
+    }
+
+    pub fn frac_digits(&self) -> Option<usize> {
+        self.frac_digits
+    }
+
+    pub fn trailing_zeros(&self) -> TrailingZeros {
+        self.trailing_zeros
+    }
+
+    pub fn group_separator(&self) -> Option<char> {
+        self.group_separator
+    }
+
+    pub fn group_size(&self) -> usize {
+        self.group_size
+    }
+
+    /// Returns exponent marker, it is `None` if the default one of radix is used.
+    pub fn exp_marker(&self) -> Option<char> {
+        self.exp_marker
+    }
+
+    /// Separates groups of integer digits, which are given from the most significant one.
+    fn group(&self, int_digits: String) -> String {
+        let separator = match self.group_separator {
+            Some(separator) if self.group_size > 0 => separator,
+            _ => return int_digits,
+        };
+
+        let mut val = String::new();
+
+        for (idx, digit) in int_digits.chars().enumerate() {
+            if idx > 0 && (int_digits.len() - idx).is_multiple_of(self.group_size) {
+                val.push(separator);
+            }
+
+            val.push(digit);
+        }
+
+        val
+    }
+}
+
+/// Returns exponent of number written in `notation`, auto notation must be resolved.
+fn exp_of<const RADIX: u32, const PREC: isize>(
+    num: &Finite<RADIX, PREC>,
+    notation: Notation,
+) -> isize {
+    match notation {
+        _ if num.is_zero() => 0,
+        Notation::Scientific => num.msd_pos(),
+        Notation::Engineering => num.msd_pos().div_euclid(3) * 3,
+        _ => 0,
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> Number<RADIX, PREC> {
+    /// Formats number with given settings.
+    ///
+    /// Exponents are decimal, NaN and infinities are formatted as `NaN`, `inf` and `-inf`.
+    pub fn format(&self, format: &NumberFormat) -> String {
+        let num = match &self.kind {
+            NumberKind::Finite(finite) => finite.clone(),
+            NumberKind::NaN => return "NaN".to_string(),
+            NumberKind::Inf => return "inf".to_string(),
+            NumberKind::NegInf => return "-inf".to_string(),
+        };
+
+        let notation = match format.notation {
+            Notation::Auto
+                if !num.is_zero() && (num.msd_pos() >= Self::prec() || num.msd_pos() < -6) =>
+            {
+                Notation::Scientific
+            }
+            Notation::Auto => Notation::Fixed,
+            notation => notation,
+        };
+
+        let num = match format.frac_digits {
+            Some(digits) => {
+                let frac_len = digits as isize - exp_of(&num, notation);
+                let mode = RoundingMode::current();
+
+                // Rounding for output is not a computation, so it raises no flags
+                match Status::isolated(|| finite_round_frac(num, frac_len, mode, Self::prec())) {
+                    Ok(num) => num,
+                    Err(overflow) => match overflow.sign {
+                        Sign::Pos => return "inf".to_string(),
+                        Sign::Neg => return "-inf".to_string(),
+                    },
+                }
+            }
+            None => num,
+        };
+
+        let exp = exp_of(&num, notation);
+        let significand = num.clone().shift_point(-exp);
+
+        let lowest_pos = match (format.frac_digits, format.trailing_zeros) {
+            (Some(digits), TrailingZeros::Keep) => -(digits as isize),
+            _ => significand.lsd_pos().min(0),
+        };
+
+        let digit_at =
+            |pos| char::from_digit(significand.get_digit(pos).unwrap_or(0), RADIX).unwrap();
+
+        let int_digits = (0..=significand.msd_pos().max(0))
+            .rev()
+            .map(digit_at)
+            .collect();
+        let frac_digits = (lowest_pos..0).rev().map(digit_at).collect::<String>();
+
+        let mut val = String::new();
+
+        if num.is_neg() {
+            val.push('-');
+        }
+
+        val.push_str(&format.group(int_digits));
+
+        if !frac_digits.is_empty() {
+            val.push('.');
+            val.push_str(&frac_digits);
+        }
+
+        if notation != Notation::Fixed {
+            val.push(format.exp_marker.unwrap_or(exp_marker_of(RADIX)));
+            val.push_str(&exp.to_string());
+        }
+
+        val
+    }
+
+    /// Writes number formatted with given settings, applies width, fill and sign flags.
+    fn fmt_with(&self, f: &mut Formatter<'_>, format: NumberFormat) -> FmtResult {
+        let val = self.format(&format);
+
+        match val.strip_prefix('-') {
+            Some(digits) => f.pad_integral(false, "", digits),
+            None => f.pad_integral(true, "", &val),
+        }
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> Display for Number<RADIX, PREC> {
+    /// Formats number as `to_string` does, precision gives count of fractional digits
+    /// of fixed notation.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let format = match f.precision() {
+            Some(digits) => NumberFormat::new()
+                .with_notation(Notation::Fixed)
+                .with_frac_digits(digits),
+            None => NumberFormat::new(),
+        };
+
+        self.fmt_with(f, format)
+    }
+}
+
+/// Formats number in scientific notation with given exponent marker,
+/// precision gives count of fractional digits.
+///
+/// Marker that is a digit of radix is replaced with the default one.
+fn fmt_exp<const RADIX: u32, const PREC: isize>(
+    num: &Number<RADIX, PREC>,
+    f: &mut Formatter<'_>,
+    exp_marker: char,
+) -> FmtResult {
+    let format = NumberFormat::new().with_notation(Notation::Scientific);
+
+    let format = match exp_marker.is_digit(RADIX) {
+        true => format,
+        false => format.with_exp_marker(exp_marker),
+    };
+
+    let format = match f.precision() {
+        Some(digits) => format.with_frac_digits(digits),
+        None => format,
+    };
+
+    num.fmt_with(f, format)
+}
+
+impl<const RADIX: u32, const PREC: isize> LowerExp for Number<RADIX, PREC> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        fmt_exp(self, f, 'e')
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> UpperExp for Number<RADIX, PREC> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        fmt_exp(self, f, 'E')
+    }
+}
@@ -0,0 +1,481 @@
+use std::cmp::Ordering;
+
+use crate::finite::{Finite, FiniteOverflow, FiniteResult};
+use crate::finite_cmp::unsigned_finite_cmp;
+use crate::finite_const::{finite_e, finite_phi, finite_pi, finite_tau};
+use crate::finite_math::{
+    finite_acosh, finite_asinh, finite_atanh, finite_cosh, finite_exp, finite_ln, finite_log,
+    finite_nth_root, finite_powi, finite_sinh, finite_sqrt, finite_tanh, GUARD_DIGITS,
+};
+use crate::finite_ops::{
+    finite_div, finite_div_rem, finite_mul, finite_round_to_prec, fit_to_prec,
+};
+use crate::number::{Number, NumberKind, OverflowToInf};
+use crate::sign::Sign;
+use crate::status::Status;
+
+impl<const RADIX: u32, const PREC: isize> Number<RADIX, PREC> {
+    /// Rounds constant computed with guard digits.
+    fn from_const(compute: fn(isize) -> FiniteResult<RADIX, PREC>) -> Self {
+        compute(Self::prec() + GUARD_DIGITS)
+            .and_then(|res| finite_round_to_prec(res, Self::prec()))
+            .overflow_to_inf()
+    }
+
+    /// Returns `π`.
+    pub fn pi() -> Self {
+        Self::from_const(finite_pi)
+    }
+
+    /// Returns `τ = 2π`.
+    pub fn tau() -> Self {
+        Self::from_const(finite_tau)
+    }
+
+    /// Returns Euler's number `e`.
+    pub fn e() -> Self {
+        Self::from_const(finite_e)
+    }
+
+    /// Returns golden ratio `φ`.
+    pub fn phi() -> Self {
+        Self::from_const(finite_phi)
+    }
+
+    /// Raises number to finite integer power.
+    ///
+    /// Negative power of number less than one by modulus is computed
+    /// as positive power of reciprocal to keep precision.
+    fn finite_powi(base: Finite<RADIX, PREC>, exp: Finite<RADIX, PREC>) -> Self {
+        let res_sign = if base.is_neg() && exp.is_odd() {
+            Sign::Neg
+        } else {
+            Sign::Pos
+        };
+
+        if base.is_zero() {
+            return match exp.is_neg() {
+                true => {
+                    Status::raise_div_by_zero();
+                    Self::inf().set_sign(res_sign)
+                }
+                false => Self::zero().set_sign(res_sign),
+            };
+        }
+
+        let prec = Self::prec() + GUARD_DIGITS + 2 * exp.int_len();
+
+        let res = if !exp.is_neg() {
+            finite_powi(base, &exp, prec)
+        } else if let Ordering::Less = unsigned_finite_cmp(&base, &Finite::one()) {
+            finite_div(Finite::one(), base, prec)
+                .and_then(|reciprocal| finite_powi(reciprocal, &exp.reverse_sign(), prec))
+        } else {
+            match finite_powi(base, &exp.reverse_sign(), prec) {
+                Ok(pow) => finite_div(Finite::one(), pow, prec),
+                Err(_) => Ok(Finite::zero().set_sign(&res_sign)),
+            }
+        };
+
+        // Overflow of intermediate powers does not carry the sign of the result
+        res.map_err(|_| FiniteOverflow { sign: res_sign })
+            .and_then(|res| finite_round_to_prec(res, Self::prec()))
+            .overflow_to_inf()
+    }
+
+    /// Raises finite number to finite power.
+    ///
+    /// Fractional power is computed as `e^(exp * ln(base))`.
+    fn finite_pow(base: Finite<RADIX, PREC>, exp: Finite<RADIX, PREC>) -> Self {
+        if exp.is_int() {
+            return Self::finite_powi(base, exp);
+        }
+
+        if base.is_zero() {
+            return match exp.is_neg() {
+                true => {
+                    Status::raise_div_by_zero();
+                    Self::inf()
+                }
+                false => Self::zero(),
+            };
+        }
+
+        if base.is_neg() {
+            return Self::invalid();
+        }
+
+        let prec = Self::prec() + GUARD_DIGITS + exp.int_len();
+
+        let arg = match finite_ln(base, prec).and_then(|ln| finite_mul(ln, exp, prec)) {
+            Ok(arg) => arg,
+            Err(overflow) if matches!(overflow.sign, Sign::Neg) => return Self::zero(),
+            Err(_) => return Self::inf(),
+        };
+
+        finite_exp(arg, prec)
+            .and_then(|res| finite_round_to_prec(res, Self::prec()))
+            .overflow_to_inf()
+    }
+
+    /// Raises number to power.
+    ///
+    /// Special values follow IEEE 754 `pow`: zero power and power of one
+    /// are always one, even for NaN, negative number to fractional power is NaN.
+    pub fn pow(self, exp: Self) -> Self {
+        match (self.kind, exp.kind) {
+            (_, NumberKind::Finite(exp)) if exp.is_zero() => Self::one(),
+            (NumberKind::Finite(base), _) if base.is_one() && !base.is_neg() => Self::one(),
+            (NumberKind::NaN, _) | (_, NumberKind::NaN) => Self::nan(),
+
+            (NumberKind::Finite(base), NumberKind::Inf) => {
+                match unsigned_finite_cmp(&base, &Finite::one()) {
+                    Ordering::Equal => Self::one(),
+                    Ordering::Greater => Self::inf(),
+                    Ordering::Less => Self::zero(),
+                }
+            }
+            (NumberKind::Finite(base), NumberKind::NegInf) => {
+                match unsigned_finite_cmp(&base, &Finite::one()) {
+                    Ordering::Equal => Self::one(),
+                    Ordering::Greater => Self::zero(),
+                    Ordering::Less => Self::inf(),
+                }
+            }
+
+            (NumberKind::Inf | NumberKind::NegInf, NumberKind::Inf) => Self::inf(),
+            (NumberKind::Inf | NumberKind::NegInf, NumberKind::NegInf) => Self::zero(),
+
+            (NumberKind::Inf, NumberKind::Finite(exp)) if exp.is_neg() => Self::zero(),
+            (NumberKind::Inf, NumberKind::Finite(_)) => Self::inf(),
+            (NumberKind::NegInf, NumberKind::Finite(exp)) => {
+                let is_odd_int = exp.is_int() && exp.is_odd();
+                let res = Self::inf().pow(Self::finite(exp));
+
+                match is_odd_int {
+                    true => -res,
+                    false => res,
+                }
+            }
+
+            (NumberKind::Finite(base), NumberKind::Finite(exp)) => Self::finite_pow(base, exp),
+        }
+    }
+
+    /// Computes `e^self`.
+    ///
+    /// Exponent of infinity is infinity, exponent of negative infinity is zero.
+    pub fn exp(self) -> Self {
+        match self.kind {
+            NumberKind::NaN => Self::nan(),
+            NumberKind::Inf => Self::inf(),
+            NumberKind::NegInf => Self::zero(),
+            NumberKind::Finite(f) => finite_exp(f, Self::prec() + GUARD_DIGITS)
+                .and_then(|res| finite_round_to_prec(res, Self::prec()))
+                .overflow_to_inf(),
+        }
+    }
+
+    /// Computes natural logarithm.
+    ///
+    /// Logarithm of zero is negative infinity, logarithm of negative number is NaN.
+    pub fn ln(self) -> Self {
+        match self.kind {
+            NumberKind::NaN => Self::nan(),
+            NumberKind::NegInf => Self::invalid(),
+            NumberKind::Inf => Self::inf(),
+            NumberKind::Finite(f) if f.is_zero() => {
+                Status::raise_div_by_zero();
+                Self::neg_inf()
+            }
+            NumberKind::Finite(f) if f.is_neg() => Self::invalid(),
+            NumberKind::Finite(f) => finite_ln(f, Self::prec() + GUARD_DIGITS)
+                .and_then(|res| finite_round_to_prec(res, Self::prec()))
+                .overflow_to_inf(),
+        }
+    }
+
+    /// Computes base-10 logarithm.
+    pub fn log10(self) -> Self {
+        self.log(Self::finite(Finite::from(10)))
+    }
+
+    /// Computes logarithm to `base`.
+    ///
+    /// Computed as `ln(self) / ln(base)`, so the result for special values
+    /// follows the division of logarithms.
+    pub fn log(self, base: Self) -> Self {
+        match (self.kind, base.kind) {
+            (NumberKind::Finite(num), NumberKind::Finite(base))
+                if num.is_pos()
+                    && !num.is_zero()
+                    && base.is_pos()
+                    && !base.is_zero()
+                    && !base.is_one() =>
+            {
+                finite_log(num, base, Self::prec() + GUARD_DIGITS)
+                    .and_then(|res| finite_round_to_prec(res, Self::prec()))
+                    .overflow_to_inf()
+            }
+            (num, base) => {
+                let (ln_num, ln_base) = (Self { kind: num }.ln(), Self { kind: base }.ln());
+
+                match ln_num.is_nan() || ln_base.is_nan() {
+                    true => Self::nan(),
+                    false => ln_num / ln_base,
+                }
+            }
+        }
+    }
+
+    /// Computes square root.
+    ///
+    /// Square root of negative number is NaN, square root of zero keeps its sign.
+    pub fn sqrt(self) -> Self {
+        match self.kind {
+            NumberKind::NaN => Self::nan(),
+            NumberKind::NegInf => Self::invalid(),
+            NumberKind::Inf => Self::inf(),
+            NumberKind::Finite(f) if f.is_zero() => Self::finite(f),
+            NumberKind::Finite(f) if f.is_neg() => Self::invalid(),
+            NumberKind::Finite(f) => finite_sqrt(f, Self::prec() + GUARD_DIGITS)
+                .and_then(|res| finite_round_to_prec(res, Self::prec()))
+                .overflow_to_inf(),
+        }
+    }
+
+    /// Computes `n`-th root.
+    ///
+    /// Root of negative number is NaN unless `n` is an odd integer,
+    /// then the root is negative. Root of zero keeps its sign.
+    /// Negative `n` gives reciprocal of the root, fractional `n` gives `self^(1/n)`,
+    /// zero or infinite `n` gives NaN.
+    pub fn nth_root(self, n: Self) -> Self {
+        let n = match n.kind {
+            NumberKind::Finite(n) if !n.is_zero() => n,
+            NumberKind::NaN => return Self::nan(),
+            _ => return Self::invalid(),
+        };
+
+        let degree = match n.to_u64() {
+            Some(degree) if n.is_int() => degree,
+            _ => return self.pow(Self::one() / Self::finite(n)),
+        };
+
+        let is_odd = n.is_odd();
+
+        match self.kind {
+            NumberKind::NaN => Self::nan(),
+            NumberKind::Inf if n.is_neg() => Self::zero(),
+            NumberKind::Inf => Self::inf(),
+            NumberKind::NegInf if is_odd && n.is_neg() => -Self::zero(),
+            NumberKind::NegInf if is_odd => Self::neg_inf(),
+            NumberKind::NegInf => Self::invalid(),
+            NumberKind::Finite(f) if f.is_zero() && n.is_neg() => {
+                Status::raise_div_by_zero();
+
+                match f.is_neg() {
+                    true => Self::neg_inf(),
+                    false => Self::inf(),
+                }
+            }
+            NumberKind::Finite(f) if f.is_zero() => Self::finite(f),
+            NumberKind::Finite(f) if f.is_neg() && !is_odd => Self::invalid(),
+            NumberKind::Finite(f) => {
+                let prec = Self::prec() + GUARD_DIGITS;
+                let res_sign = if f.is_neg() { Sign::Neg } else { Sign::Pos };
+
+                let res = finite_nth_root(f.set_sign(&Sign::Pos), degree, prec);
+                let res = match n.is_neg() {
+                    true => res.and_then(|root| finite_div(Finite::one(), root, prec)),
+                    false => res,
+                };
+
+                res.and_then(|res| finite_round_to_prec(res.set_sign(&res_sign), Self::prec()))
+                    .overflow_to_inf()
+            }
+        }
+    }
+
+    /// Computes hyperbolic sine.
+    ///
+    /// Overflows to signed infinity, hyperbolic sine of zero keeps its sign.
+    pub fn sinh(self) -> Self {
+        match self.kind {
+            NumberKind::Finite(f) if f.is_zero() => Self::finite(f),
+            NumberKind::Finite(f) => finite_sinh(f, Self::prec() + GUARD_DIGITS)
+                .and_then(|res| finite_round_to_prec(res, Self::prec()))
+                .overflow_to_inf(),
+            kind => Self { kind },
+        }
+    }
+
+    /// Computes hyperbolic cosine.
+    ///
+    /// Overflows to infinity.
+    pub fn cosh(self) -> Self {
+        match self.kind {
+            NumberKind::NaN => Self::nan(),
+            NumberKind::Inf | NumberKind::NegInf => Self::inf(),
+            NumberKind::Finite(f) => finite_cosh(f, Self::prec() + GUARD_DIGITS)
+                .and_then(|res| finite_round_to_prec(res, Self::prec()))
+                .overflow_to_inf(),
+        }
+    }
+
+    /// Computes hyperbolic tangent.
+    ///
+    /// Hyperbolic tangent of infinity is one, hyperbolic tangent of zero keeps its sign.
+    pub fn tanh(self) -> Self {
+        match self.kind {
+            NumberKind::NaN => Self::nan(),
+            NumberKind::Inf => Self::one(),
+            NumberKind::NegInf => -Self::one(),
+            NumberKind::Finite(f) if f.is_zero() => Self::finite(f),
+            NumberKind::Finite(f) => finite_tanh(f, Self::prec() + GUARD_DIGITS)
+                .and_then(|res| finite_round_to_prec(res, Self::prec()))
+                .overflow_to_inf(),
+        }
+    }
+
+    /// Computes inverse hyperbolic sine.
+    ///
+    /// Inverse hyperbolic sine of zero keeps its sign.
+    pub fn asinh(self) -> Self {
+        match self.kind {
+            NumberKind::Finite(f) if f.is_zero() => Self::finite(f),
+            NumberKind::Finite(f) => finite_asinh(f, Self::prec() + GUARD_DIGITS)
+                .and_then(|res| finite_round_to_prec(res, Self::prec()))
+                .overflow_to_inf(),
+            kind => Self { kind },
+        }
+    }
+
+    /// Computes inverse hyperbolic cosine.
+    ///
+    /// Inverse hyperbolic cosine of number less than one is NaN.
+    pub fn acosh(self) -> Self {
+        match self.kind {
+            NumberKind::NaN => Self::nan(),
+            NumberKind::NegInf => Self::invalid(),
+            NumberKind::Inf => Self::inf(),
+            NumberKind::Finite(f)
+                if f.is_neg() || unsigned_finite_cmp(&f, &Finite::one()) == Ordering::Less =>
+            {
+                Self::invalid()
+            }
+            NumberKind::Finite(f) => finite_acosh(f, Self::prec() + GUARD_DIGITS)
+                .and_then(|res| finite_round_to_prec(res, Self::prec()))
+                .overflow_to_inf(),
+        }
+    }
+
+    /// Computes inverse hyperbolic tangent.
+    ///
+    /// Inverse hyperbolic tangent of `±1` is signed infinity,
+    /// of number greater than one by modulus is NaN, of zero keeps its sign.
+    pub fn atanh(self) -> Self {
+        match self.kind {
+            NumberKind::Finite(f) if f.is_zero() => Self::finite(f),
+            NumberKind::Finite(f) => match unsigned_finite_cmp(&f, &Finite::one()) {
+                Ordering::Less => finite_atanh(f, Self::prec() + GUARD_DIGITS)
+                    .and_then(|res| finite_round_to_prec(res, Self::prec()))
+                    .overflow_to_inf(),
+                Ordering::Equal => {
+                    Status::raise_div_by_zero();
+                    Self::inf().set_sign(if f.is_neg() { Sign::Neg } else { Sign::Pos })
+                }
+                Ordering::Greater => Self::invalid(),
+            },
+            NumberKind::NaN => Self::nan(),
+            _ => Self::invalid(),
+        }
+    }
+
+    /// Divides number by `rhs`, returns quotient truncated to integer
+    /// and remainder that has the sign of dividend.
+    ///
+    /// Remainder of division by zero or of infinity is NaN,
+    /// remainder of finite number divided by infinity is the number.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        self.div_rem_by(rhs, false)
+    }
+
+    /// Divides number by `rhs` like `div_rem`, but quotient of finite numbers
+    /// is rounded toward negative infinity if `is_floor`, before it is rounded to precision.
+    fn div_rem_by(self, rhs: Self, is_floor: bool) -> (Self, Self) {
+        match (&self.kind, &rhs.kind) {
+            (NumberKind::NaN, _) | (_, NumberKind::NaN) => (Self::nan(), Self::nan()),
+            (NumberKind::Finite(lhs), NumberKind::Finite(rhs)) if !rhs.is_zero() => {
+                match finite_div_rem(lhs.clone(), rhs.clone(), is_floor, Self::prec()) {
+                    Ok((quotient, rem)) => (
+                        fit_to_prec(quotient, Self::prec()).overflow_to_inf(),
+                        Self::finite(rem),
+                    ),
+                    Err(_) => (Self::invalid(), Self::invalid()),
+                }
+            }
+            (NumberKind::Finite(_), NumberKind::Inf | NumberKind::NegInf) => {
+                ((self.clone() / rhs).trunc(), self)
+            }
+            _ => ((self / rhs).trunc(), Self::invalid()),
+        }
+    }
+
+    /// Computes remainder of `div_rem_by`, does not round quotient,
+    /// so that flags of rounding it are not raised.
+    pub(crate) fn rem_by(self, rhs: Self, is_floor: bool) -> Self {
+        match (&self.kind, &rhs.kind) {
+            (NumberKind::NaN, _) | (_, NumberKind::NaN) => Self::nan(),
+            (NumberKind::Finite(lhs), NumberKind::Finite(rhs)) if !rhs.is_zero() => {
+                match finite_div_rem(lhs.clone(), rhs.clone(), is_floor, Self::prec()) {
+                    Ok((_, rem)) => Self::finite(rem),
+                    Err(_) => Self::invalid(),
+                }
+            }
+            (NumberKind::Finite(_), NumberKind::Inf | NumberKind::NegInf) => self,
+            _ => Self::invalid(),
+        }
+    }
+
+    /// Checks if remainder is not zero and its sign differs from the sign of divisor.
+    fn is_rem_opposite(rem: &Self, divisor_sign: &Option<Sign>) -> bool {
+        match (&rem.kind, divisor_sign) {
+            (NumberKind::Finite(rem), Some(Sign::Pos)) => rem.is_neg() && !rem.is_zero(),
+            (NumberKind::Finite(rem), Some(Sign::Neg)) => !rem.is_neg() && !rem.is_zero(),
+            _ => false,
+        }
+    }
+
+    /// Divides number by `rhs` and rounds quotient toward negative infinity.
+    pub fn div_floor(self, rhs: Self) -> Self {
+        let rhs_sign = rhs.sign();
+        let (quotient, rem) = self.div_rem_by(rhs, true);
+
+        match Self::is_rem_opposite(&rem, &rhs_sign) {
+            true => quotient - Self::one(),
+            false => quotient,
+        }
+    }
+
+    /// Computes remainder of floor division, it has the sign of divisor.
+    pub fn modulo(self, rhs: Self) -> Self {
+        let rhs_sign = rhs.sign();
+        let rem = self.rem_by(rhs.clone(), true);
+
+        match Self::is_rem_opposite(&rem, &rhs_sign) {
+            true => rem + rhs,
+            false => rem,
+        }
+    }
+
+    /// Computes non-negative remainder of division.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let rem = self.rem_by(rhs.clone(), false);
+
+        match Self::is_rem_opposite(&rem, &Some(Sign::Pos)) {
+            true => rem + rhs.abs(),
+            false => rem,
+        }
+    }
+}
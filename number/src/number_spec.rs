@@ -0,0 +1,82 @@
+use std::cell::Cell;
+
+use crate::number::Number;
+
+/// Precision of `Number` that follows precision of the current `NumberSpec`.
+///
+/// It is a marker, numbers of this precision are only kept inside `DynNumber`.
+const DYN_PREC: isize = isize::MIN;
+
+/// Number whose precision follows precision of the current `NumberSpec`.
+pub(crate) type DynPrecNumber<const RADIX: u32> = Number<RADIX, DYN_PREC>;
+
+/// Radix and precision of numbers chosen at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumberSpec {
+    radix: u32,
+    prec: isize,
+}
+
+impl NumberSpec {
+    pub const DEFAULT: Self = Self {
+        radix: 10,
+        prec: 64,
+    };
+
+    /// Returns `None` if radix is not in `2..=36` or precision is not positive.
+    pub fn new(radix: u32, prec: isize) -> Option<Self> {
+        match (2..=36).contains(&radix) && prec > 0 {
+            true => Some(Self { radix, prec }),
+            false => None,
+        }
+    }
+
+    pub fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    pub fn prec(&self) -> isize {
+        self.prec
+    }
+}
+
+impl Default for NumberSpec {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+thread_local! {
+    static CURRENT: Cell<NumberSpec> = Cell::new(NumberSpec::default());
+}
+
+impl NumberSpec {
+    /// Returns radix and precision of numbers in the current thread.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.get())
+    }
+
+    /// Sets radix and precision of numbers in the current thread.
+    ///
+    /// Returns the previous spec.
+    pub fn set_current(self) -> Self {
+        CURRENT.with(|current| current.replace(self))
+    }
+
+    /// Runs `f` with this spec set as the current one.
+    pub(crate) fn within<T>(self, f: impl FnOnce() -> T) -> T {
+        let prev = self.set_current();
+        let res = f();
+        prev.set_current();
+
+        res
+    }
+}
+
+/// Returns `PREC` or precision of the current `NumberSpec` if `PREC` is `DYN_PREC`.
+pub(crate) fn prec_of<const PREC: isize>() -> isize {
+    match PREC {
+        DYN_PREC => NumberSpec::current().prec(),
+        _ => PREC,
+    }
+}
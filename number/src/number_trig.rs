@@ -0,0 +1,213 @@
+use std::cmp::Ordering;
+
+use crate::finite::{Finite, FiniteResult};
+use crate::finite_cmp::unsigned_finite_cmp;
+use crate::finite_const::finite_pi;
+use crate::finite_math::GUARD_DIGITS;
+use crate::finite_ops::{finite_div, finite_mul, finite_round_to_prec};
+use crate::finite_trig::{
+    finite_acos, finite_asin, finite_atan, finite_atan2, finite_cos, finite_sin, finite_tan,
+    AngleUnit,
+};
+use crate::number::{Number, NumberKind, OverflowToInf};
+use crate::sign::Sign;
+
+impl<const RADIX: u32, const PREC: isize> Number<RADIX, PREC> {
+    /// Rounds result of trigonometric function computed with guard digits.
+    fn from_trig_res(res: FiniteResult<RADIX, PREC>) -> Self {
+        res.and_then(|res| finite_round_to_prec(res, Self::prec()))
+            .overflow_to_inf()
+    }
+
+    /// Returns `π * mul / div` in given angle unit.
+    fn pi_fraction(mul: u64, div: u64, sign: Sign, unit: AngleUnit) -> Self {
+        let prec = Self::prec() + GUARD_DIGITS;
+
+        let res = finite_pi(prec)
+            .and_then(|pi| finite_mul(pi, Finite::from(mul), prec))
+            .and_then(|pi| finite_div(pi, Finite::from(div), prec))
+            .and_then(|res| unit.angle_from_radians(res, prec))
+            .map(|res| res.set_sign(&sign));
+
+        Self::from_trig_res(res)
+    }
+
+    /// Converts angle in degrees to radians.
+    pub fn to_radians(self) -> Self {
+        match self.kind {
+            NumberKind::Finite(f) => Self::from_trig_res(
+                AngleUnit::Degrees.angle_to_radians(f, Self::prec() + GUARD_DIGITS),
+            ),
+            kind => Self { kind },
+        }
+    }
+
+    /// Converts angle in radians to degrees.
+    pub fn to_degrees(self) -> Self {
+        match self.kind {
+            NumberKind::Finite(f) => Self::from_trig_res(
+                AngleUnit::Degrees.angle_from_radians(f, Self::prec() + GUARD_DIGITS),
+            ),
+            kind => Self { kind },
+        }
+    }
+
+    /// Computes sine of angle in radians.
+    pub fn sin(self) -> Self {
+        self.sin_in(AngleUnit::Radians)
+    }
+
+    /// Computes sine of angle in given unit.
+    ///
+    /// Sine of infinity is NaN, sine of zero keeps its sign.
+    pub fn sin_in(self, unit: AngleUnit) -> Self {
+        match self.kind {
+            NumberKind::NaN => Self::nan(),
+            NumberKind::Inf | NumberKind::NegInf => Self::invalid(),
+            NumberKind::Finite(f) if f.is_zero() => Self::finite(f),
+            NumberKind::Finite(f) => {
+                Self::from_trig_res(finite_sin(f, unit, Self::prec() + GUARD_DIGITS))
+            }
+        }
+    }
+
+    /// Computes cosine of angle in radians.
+    pub fn cos(self) -> Self {
+        self.cos_in(AngleUnit::Radians)
+    }
+
+    /// Computes cosine of angle in given unit.
+    ///
+    /// Cosine of infinity is NaN.
+    pub fn cos_in(self, unit: AngleUnit) -> Self {
+        match self.kind {
+            NumberKind::NaN => Self::nan(),
+            NumberKind::Inf | NumberKind::NegInf => Self::invalid(),
+            NumberKind::Finite(f) => {
+                Self::from_trig_res(finite_cos(f, unit, Self::prec() + GUARD_DIGITS))
+            }
+        }
+    }
+
+    /// Computes tangent of angle in radians.
+    pub fn tan(self) -> Self {
+        self.tan_in(AngleUnit::Radians)
+    }
+
+    /// Computes tangent of angle in given unit.
+    ///
+    /// Tangent of infinity is NaN, tangent of zero keeps its sign.
+    pub fn tan_in(self, unit: AngleUnit) -> Self {
+        match self.kind {
+            NumberKind::NaN => Self::nan(),
+            NumberKind::Inf | NumberKind::NegInf => Self::invalid(),
+            NumberKind::Finite(f) if f.is_zero() => Self::finite(f),
+            NumberKind::Finite(f) => {
+                Self::from_trig_res(finite_tan(f, unit, Self::prec() + GUARD_DIGITS))
+            }
+        }
+    }
+
+    /// Computes arcsine in radians.
+    pub fn asin(self) -> Self {
+        self.asin_in(AngleUnit::Radians)
+    }
+
+    /// Computes arcsine in given unit.
+    ///
+    /// Arcsine of number greater than one by modulus is NaN, arcsine of zero keeps its sign.
+    pub fn asin_in(self, unit: AngleUnit) -> Self {
+        let prec = Self::prec() + GUARD_DIGITS;
+
+        match self.kind {
+            NumberKind::Finite(f) if f.is_zero() => Self::finite(f),
+            NumberKind::Finite(f)
+                if unsigned_finite_cmp(&f, &Finite::one()) != Ordering::Greater =>
+            {
+                Self::from_trig_res(
+                    finite_asin(f, prec).and_then(|res| unit.angle_from_radians(res, prec)),
+                )
+            }
+            NumberKind::NaN => Self::nan(),
+            _ => Self::invalid(),
+        }
+    }
+
+    /// Computes arccosine in radians.
+    pub fn acos(self) -> Self {
+        self.acos_in(AngleUnit::Radians)
+    }
+
+    /// Computes arccosine in given unit.
+    ///
+    /// Arccosine of number greater than one by modulus is NaN.
+    pub fn acos_in(self, unit: AngleUnit) -> Self {
+        let prec = Self::prec() + GUARD_DIGITS;
+
+        match self.kind {
+            NumberKind::Finite(f)
+                if unsigned_finite_cmp(&f, &Finite::one()) != Ordering::Greater =>
+            {
+                Self::from_trig_res(
+                    finite_acos(f, prec).and_then(|res| unit.angle_from_radians(res, prec)),
+                )
+            }
+            NumberKind::NaN => Self::nan(),
+            _ => Self::invalid(),
+        }
+    }
+
+    /// Computes arctangent in radians.
+    pub fn atan(self) -> Self {
+        self.atan_in(AngleUnit::Radians)
+    }
+
+    /// Computes arctangent in given unit.
+    ///
+    /// Arctangent of infinity is a quarter turn, arctangent of zero keeps its sign.
+    pub fn atan_in(self, unit: AngleUnit) -> Self {
+        let prec = Self::prec() + GUARD_DIGITS;
+
+        match self.kind {
+            NumberKind::NaN => Self::nan(),
+            NumberKind::Inf => Self::pi_fraction(1, 2, Sign::Pos, unit),
+            NumberKind::NegInf => Self::pi_fraction(1, 2, Sign::Neg, unit),
+            NumberKind::Finite(f) if f.is_zero() => Self::finite(f),
+            NumberKind::Finite(f) => Self::from_trig_res(
+                finite_atan(f, prec).and_then(|res| unit.angle_from_radians(res, prec)),
+            ),
+        }
+    }
+
+    /// Computes angle between positive x axis and ray to point `(x, self)` in radians.
+    pub fn atan2(self, x: Self) -> Self {
+        self.atan2_in(x, AngleUnit::Radians)
+    }
+
+    /// Computes angle between positive x axis and ray to point `(x, self)` in given unit.
+    ///
+    /// Special values follow IEEE 754 `atan2`.
+    pub fn atan2_in(self, x: Self, unit: AngleUnit) -> Self {
+        let prec = Self::prec() + GUARD_DIGITS;
+
+        let y_sign = self.sign().unwrap_or(Sign::Pos);
+
+        match (self.kind, x.kind) {
+            (NumberKind::NaN, _) | (_, NumberKind::NaN) => Self::nan(),
+            (NumberKind::Inf | NumberKind::NegInf, NumberKind::Inf) => {
+                Self::pi_fraction(1, 4, y_sign, unit)
+            }
+            (NumberKind::Inf | NumberKind::NegInf, NumberKind::NegInf) => {
+                Self::pi_fraction(3, 4, y_sign, unit)
+            }
+            (NumberKind::Inf | NumberKind::NegInf, NumberKind::Finite(_)) => {
+                Self::pi_fraction(1, 2, y_sign, unit)
+            }
+            (NumberKind::Finite(_), NumberKind::Inf) => Self::zero().set_sign(y_sign),
+            (NumberKind::Finite(_), NumberKind::NegInf) => Self::pi_fraction(1, 1, y_sign, unit),
+            (NumberKind::Finite(y), NumberKind::Finite(x)) => Self::from_trig_res(
+                finite_atan2(y, x, prec).and_then(|res| unit.angle_from_radians(res, prec)),
+            ),
+        }
+    }
+}
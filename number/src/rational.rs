@@ -0,0 +1,656 @@
+use std::cmp::{Ordering, PartialEq, PartialOrd};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::exp_range::ExpRange;
+use crate::finite::{Finite, TryFromStrError};
+use crate::finite_ops::finite_div;
+use crate::limbs::{
+    limbs_add, limbs_cmp, limbs_convert, limbs_digit, limbs_digits_len, limbs_div_rem,
+    limbs_from_u64, limbs_gcd, limbs_is_odd, limbs_mul, limbs_nth_root, limbs_pow,
+    limbs_shl_digits, limbs_sub,
+};
+use crate::number::{Number, NumberKind, OverflowToInf};
+use crate::number_spec::prec_of;
+use crate::sign::Sign;
+
+/// Radix of digits packed into limbs of numerator and denominator.
+const LIMBS_RADIX: u32 = 10;
+
+/// Exact fraction of arbitrary integers.
+///
+/// Fraction is kept in lowest terms with non-negative denominator.
+/// Infinities are `±1/0` and NaN is `0/0`, so arithmetic of fractions
+/// handles special values the same way as `Number` does.
+#[derive(Clone, Debug)]
+pub struct Rational {
+    sign: Sign,
+    /// Limbs of absolute value of numerator, the least significant first.
+    numer: Vec<u32>,
+    /// Limbs of denominator, the least significant first.
+    denom: Vec<u32>,
+}
+
+impl TryFrom<&str> for Rational {
+    type Error = TryFromStrError<LIMBS_RADIX, 1>;
+
+    /// Parses decimal literal, the value is exact for any count of digits.
+    ///
+    /// Literals whose most significant digit is out of the current `ExpRange`
+    /// are rejected, as their exact values may not fit in memory.
+    fn try_from(src: &str) -> Result<Self, Self::Error> {
+        let finite = Finite::<LIMBS_RADIX, 1>::try_from(src)?;
+        let range = ExpRange::current();
+
+        if !finite.is_zero() && !(range.emin()..=range.emax()).contains(&finite.msd_pos()) {
+            return Err(TryFromStrError::ExponentOutOfRange);
+        }
+
+        Ok(Self::from_finite(&finite))
+    }
+}
+
+impl TryFrom<String> for Rational {
+    type Error = TryFromStrError<LIMBS_RADIX, 1>;
+
+    fn try_from(src: String) -> Result<Self, Self::Error> {
+        Self::try_from(src.as_str())
+    }
+}
+
+impl<const RADIX: u32, const PREC: isize> From<Number<RADIX, PREC>> for Rational {
+    fn from(num: Number<RADIX, PREC>) -> Self {
+        match num.kind {
+            NumberKind::Finite(finite) => Self::from_finite(&finite),
+            NumberKind::Inf => Self::inf(),
+            NumberKind::NegInf => -Self::inf(),
+            NumberKind::NaN => Self::nan(),
+        }
+    }
+}
+
+impl Display for Rational {
+    /// Formats number as `numerator/denominator`, integers without denominator.
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.is_nan() {
+            return write!(f, "NaN");
+        }
+
+        if matches!(self.sign, Sign::Neg) {
+            write!(f, "-")?;
+        }
+
+        if self.denom.is_empty() {
+            return write!(f, "inf");
+        }
+
+        write!(f, "{}", Self::int_to_string(&self.numer))?;
+
+        if !self.is_int() {
+            write!(f, "/{}", Self::int_to_string(&self.denom))?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Rational {
+    /// Builds fraction and reduces it to lowest terms.
+    fn new(sign: Sign, numer: Vec<u32>, denom: Vec<u32>) -> Self {
+        let gcd = limbs_gcd::<LIMBS_RADIX>(&numer, &denom);
+
+        let (numer, denom) = match gcd.as_slice() {
+            [] => return Self::nan(),
+            [1] => (numer, denom),
+            _ => (
+                limbs_div_rem::<LIMBS_RADIX>(&numer, &gcd).0,
+                limbs_div_rem::<LIMBS_RADIX>(&denom, &gcd).0,
+            ),
+        };
+
+        let sign = match numer.is_empty() {
+            true => Sign::Pos,
+            false => sign,
+        };
+
+        Self { sign, numer, denom }
+    }
+
+    fn from_finite<const RADIX: u32, const PREC: isize>(finite: &Finite<RADIX, PREC>) -> Self {
+        let sign = match finite.is_neg() {
+            true => Sign::Neg,
+            false => Sign::Pos,
+        };
+
+        let mantissa = limbs_convert::<RADIX, LIMBS_RADIX>(finite.limbs());
+        let exp = finite.lsd_pos();
+
+        let scale = match RADIX == LIMBS_RADIX {
+            true => limbs_shl_digits::<LIMBS_RADIX>(&[1], exp.unsigned_abs()),
+            false => limbs_pow::<LIMBS_RADIX>(
+                &limbs_from_u64::<LIMBS_RADIX>(RADIX as u64),
+                exp.unsigned_abs() as u64,
+            ),
+        };
+
+        match exp >= 0 {
+            true => Self::new(sign, limbs_mul::<LIMBS_RADIX>(&mantissa, &scale), vec![1]),
+            false => Self::new(sign, mantissa, scale),
+        }
+    }
+
+    fn int_to_string(limbs: &[u32]) -> String {
+        let len = limbs_digits_len::<LIMBS_RADIX>(limbs).max(1);
+
+        (0..len)
+            .rev()
+            .map(|pos| char::from_digit(limbs_digit::<LIMBS_RADIX>(limbs, pos), LIMBS_RADIX))
+            .map(Option::unwrap)
+            .collect()
+    }
+
+    /// Returns approximate base-10 logarithm of integer, zero for zero.
+    fn approx_log(limbs: &[u32]) -> f64 {
+        let digits = Self::int_to_string(limbs);
+        let leading_len = digits.len().min(15);
+        let leading = digits[..leading_len].parse::<f64>().unwrap_or(0.0).max(1.0);
+
+        leading.log10() + (digits.len() - leading_len) as f64
+    }
+
+    pub fn inf() -> Self {
+        Self {
+            sign: Sign::Pos,
+            numer: vec![1],
+            denom: Vec::new(),
+        }
+    }
+
+    pub fn nan() -> Self {
+        Self {
+            sign: Sign::Pos,
+            numer: Vec::new(),
+            denom: Vec::new(),
+        }
+    }
+
+    pub fn zero() -> Self {
+        Self {
+            sign: Sign::Pos,
+            numer: Vec::new(),
+            denom: vec![1],
+        }
+    }
+
+    pub fn one() -> Self {
+        Self {
+            sign: Sign::Pos,
+            numer: vec![1],
+            denom: vec![1],
+        }
+    }
+
+    pub fn is_nan(&self) -> bool {
+        self.numer.is_empty() && self.denom.is_empty()
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        !self.numer.is_empty() && self.denom.is_empty()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numer.is_empty() && !self.denom.is_empty()
+    }
+
+    /// Checks if number is finite integer.
+    pub fn is_int(&self) -> bool {
+        self.denom == [1]
+    }
+
+    /// Returns numerator, it has the sign of number.
+    pub fn numer(&self) -> Self {
+        Self::new(self.sign.clone(), self.numer.clone(), vec![1])
+    }
+
+    /// Returns denominator, it is zero for special values.
+    pub fn denom(&self) -> Self {
+        Self::new(Sign::Pos, self.denom.clone(), vec![1])
+    }
+
+    /// Approximates number by `Number`, rounds with the current rounding mode.
+    pub fn to_number<const RADIX: u32, const PREC: isize>(&self) -> Number<RADIX, PREC> {
+        if self.is_nan() {
+            return Number::nan();
+        }
+
+        if self.is_infinite() {
+            return match self.sign {
+                Sign::Pos => Number::inf(),
+                Sign::Neg => Number::neg_inf(),
+            };
+        }
+
+        let numer = Finite::from_limbs(limbs_convert::<LIMBS_RADIX, RADIX>(&self.numer), 0)
+            .set_sign(&self.sign);
+        let denom = Finite::from_limbs(limbs_convert::<LIMBS_RADIX, RADIX>(&self.denom), 0);
+
+        finite_div(numer, denom, prec_of::<PREC>()).overflow_to_inf()
+    }
+
+    pub fn abs(mut self) -> Self {
+        self.sign = Sign::Pos;
+        self
+    }
+
+    /// Returns quotient of numerator and denominator truncated to integer
+    /// and the remainder.
+    fn int_rem(&self) -> (Vec<u32>, Vec<u32>) {
+        limbs_div_rem::<LIMBS_RADIX>(&self.numer, &self.denom)
+    }
+
+    /// Returns integer part of number moved away from zero by `step`.
+    fn int_with_step(self, step: bool) -> Self {
+        if self.is_nan() || self.is_infinite() {
+            return self;
+        }
+
+        let (int, _) = self.int_rem();
+
+        let int = match step {
+            true => limbs_add::<LIMBS_RADIX>(&int, &[1]),
+            false => int,
+        };
+
+        Self::new(self.sign, int, vec![1])
+    }
+
+    /// Returns integer part of number.
+    pub fn trunc(self) -> Self {
+        self.int_with_step(false)
+    }
+
+    /// Returns the largest integer less than or equal to number.
+    pub fn floor(self) -> Self {
+        let step = matches!(self.sign, Sign::Neg) && !self.is_int();
+        self.int_with_step(step)
+    }
+
+    /// Returns the smallest integer greater than or equal to number.
+    pub fn ceil(self) -> Self {
+        let step = matches!(self.sign, Sign::Pos) && !self.is_int();
+        self.int_with_step(step)
+    }
+
+    /// Returns the nearest integer to number, rounds half away from zero.
+    pub fn round(self) -> Self {
+        if self.is_nan() || self.is_infinite() {
+            return self;
+        }
+
+        let (_, rem) = self.int_rem();
+        let step = limbs_cmp(&limbs_add::<LIMBS_RADIX>(&rem, &rem), &self.denom) != Ordering::Less;
+
+        self.int_with_step(step)
+    }
+
+    /// Raises number to integer power.
+    ///
+    /// Fractional and infinite powers are irrational in general, so they are NaN.
+    /// Powers with more digits than the maximal exponent of the current `ExpRange`
+    /// round to infinity or zero, as they do for `Number`.
+    pub fn pow(self, exp: Self) -> Self {
+        if exp.is_zero() {
+            return Self::one();
+        }
+
+        if !exp.is_int() || self.is_nan() {
+            return Self::nan();
+        }
+
+        let Ok(exp_int) = Self::int_to_string(&exp.numer).parse::<u64>() else {
+            return self.pow_overflow(&exp);
+        };
+
+        let len = Self::approx_log(&self.numer).max(Self::approx_log(&self.denom));
+
+        if len * exp_int as f64 > ExpRange::current().emax() as f64 {
+            return self.pow_overflow(&exp);
+        }
+
+        let sign = match exp_int % 2 {
+            1 => self.sign.clone(),
+            _ => Sign::Pos,
+        };
+
+        let (numer, denom) = (
+            limbs_pow::<LIMBS_RADIX>(&self.numer, exp_int),
+            limbs_pow::<LIMBS_RADIX>(&self.denom, exp_int),
+        );
+
+        match exp.sign {
+            Sign::Pos => Self::new(sign, numer, denom),
+            Sign::Neg => Self::new(sign, denom, numer),
+        }
+    }
+
+    /// Raises number to fractional power `p/q` as `q`-th root raised to `p`.
+    ///
+    /// Returns `None` if the root is irrational. Fractional powers of negative numbers
+    /// are NaN, as they are for `Number`.
+    pub fn pow_ratio(self, exp: Self) -> Option<Self> {
+        if exp.is_int() || exp.is_nan() || self.is_nan() {
+            return Some(self.pow(exp));
+        }
+
+        if exp.is_infinite() {
+            return None;
+        }
+
+        if matches!(self.sign, Sign::Neg) {
+            return Some(Self::nan());
+        }
+
+        let degree = Self::new(Sign::Pos, exp.denom, vec![1]);
+        let power = Self::new(exp.sign, exp.numer, vec![1]);
+
+        self.nth_root(degree).map(|root| root.pow(power))
+    }
+
+    /// Computes square root, returns `None` if it is irrational.
+    pub fn sqrt(self) -> Option<Self> {
+        self.nth_root(Self::new(Sign::Pos, vec![2], vec![1]))
+    }
+
+    /// Computes `n`-th root, returns `None` if it is irrational.
+    ///
+    /// Special values and signs follow `Number::nth_root`: root of negative number
+    /// is NaN unless `n` is an odd integer, negative `n` gives reciprocal of the root,
+    /// fractional `n` gives `self^(1/n)`, zero or infinite `n` gives NaN.
+    pub fn nth_root(self, n: Self) -> Option<Self> {
+        if self.is_nan() || n.is_nan() || n.is_zero() || n.is_infinite() {
+            return Some(Self::nan());
+        }
+
+        if !n.is_int() {
+            return self.pow_ratio(Self::one() / n);
+        }
+
+        let is_odd = limbs_is_odd::<LIMBS_RADIX>(&n.numer);
+
+        if matches!(self.sign, Sign::Neg) && !is_odd {
+            return Some(Self::nan());
+        }
+
+        if self.is_infinite() || self.is_zero() {
+            return Some(match n.sign {
+                Sign::Pos => self,
+                Sign::Neg => Self::one() / self,
+            });
+        }
+
+        // Roots of degree above `u64::MAX` are irrational unless the number is one
+        let Ok(degree) = Self::int_to_string(&n.numer).parse::<u64>() else {
+            return match self.numer == [1] && self.denom == [1] {
+                true => Some(self),
+                false => None,
+            };
+        };
+
+        let numer = limbs_nth_root::<LIMBS_RADIX>(&self.numer, degree);
+        let denom = limbs_nth_root::<LIMBS_RADIX>(&self.denom, degree);
+
+        let is_exact = limbs_pow::<LIMBS_RADIX>(&numer, degree) == self.numer
+            && limbs_pow::<LIMBS_RADIX>(&denom, degree) == self.denom;
+
+        match (is_exact, n.sign) {
+            (false, _) => None,
+            (true, Sign::Pos) => Some(Self::new(self.sign, numer, denom)),
+            (true, Sign::Neg) => Some(Self::new(self.sign, denom, numer)),
+        }
+    }
+
+    /// Returns power too long to be computed, it is infinity if power grows
+    /// by modulus and zero if it falls.
+    fn pow_overflow(self, exp: &Self) -> Self {
+        let grows = limbs_cmp(&self.numer, &self.denom) == Ordering::Greater;
+        let is_odd = exp.numer.first().is_some_and(|limb| limb % 2 == 1);
+
+        let res = match grows == matches!(exp.sign, Sign::Pos) {
+            true => Self::inf(),
+            false => Self::zero(),
+        };
+
+        match matches!(self.sign, Sign::Neg) && is_odd {
+            true => -res,
+            false => res,
+        }
+    }
+
+    /// Divides number by `rhs`, returns quotient truncated to integer
+    /// and remainder that has the sign of dividend.
+    ///
+    /// Remainder of division by zero or of infinity is NaN,
+    /// remainder of finite number divided by infinity is the number.
+    pub fn div_rem(self, rhs: Self) -> (Self, Self) {
+        if self.is_nan() || rhs.is_nan() {
+            return (Self::nan(), Self::nan());
+        }
+
+        if self.is_infinite() || rhs.is_zero() {
+            return ((self / rhs).trunc(), Self::nan());
+        }
+
+        let quotient = (self.clone() / rhs.clone()).trunc();
+
+        match rhs.is_infinite() {
+            true => (quotient, self),
+            false => (quotient.clone(), self - quotient * rhs),
+        }
+    }
+
+    /// Checks if remainder is not zero and its sign differs from the sign of divisor.
+    fn is_rem_opposite(rem: &Self, divisor_sign: &Sign) -> bool {
+        !rem.is_zero() && !rem.is_nan() && !rem.sign.eq(divisor_sign)
+    }
+
+    /// Divides number by `rhs` and rounds quotient toward negative infinity.
+    pub fn div_floor(self, rhs: Self) -> Self {
+        let rhs_sign = rhs.sign.clone();
+        let (quotient, rem) = self.div_rem(rhs);
+
+        match Self::is_rem_opposite(&rem, &rhs_sign) {
+            true => quotient - Self::one(),
+            false => quotient,
+        }
+    }
+
+    /// Computes remainder of floor division, it has the sign of divisor.
+    pub fn modulo(self, rhs: Self) -> Self {
+        let rhs_sign = rhs.sign.clone();
+        let (_, rem) = self.div_rem(rhs.clone());
+
+        match Self::is_rem_opposite(&rem, &rhs_sign) {
+            true => rem + rhs,
+            false => rem,
+        }
+    }
+
+    /// Computes non-negative remainder of division.
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let (_, rem) = self.div_rem(rhs.clone());
+
+        match Self::is_rem_opposite(&rem, &Sign::Pos) {
+            true => rem + rhs.abs(),
+            false => rem,
+        }
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl PartialEq for Rational {
+    fn eq(&self, rhs: &Self) -> bool {
+        matches!(self.partial_cmp(rhs), Some(Ordering::Equal))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        if self.is_nan() || rhs.is_nan() {
+            return None;
+        }
+
+        if self.is_infinite() && rhs.is_infinite() {
+            let ord = match (&self.sign, &rhs.sign) {
+                (Sign::Pos, Sign::Neg) => Ordering::Greater,
+                (Sign::Neg, Sign::Pos) => Ordering::Less,
+                _ => Ordering::Equal,
+            };
+
+            return Some(ord);
+        }
+
+        // Denominators are non-negative, so `a/b < c/d` if `a*d < c*b`
+        let lhs_cross = limbs_mul::<LIMBS_RADIX>(&self.numer, &rhs.denom);
+        let rhs_cross = limbs_mul::<LIMBS_RADIX>(&rhs.numer, &self.denom);
+
+        let ord = match (&self.sign, &rhs.sign) {
+            _ if lhs_cross.is_empty() && rhs_cross.is_empty() => Ordering::Equal,
+            (Sign::Pos, Sign::Pos) => limbs_cmp(&lhs_cross, &rhs_cross),
+            (Sign::Neg, Sign::Neg) => limbs_cmp(&rhs_cross, &lhs_cross),
+            (Sign::Pos, Sign::Neg) => Ordering::Greater,
+            (Sign::Neg, Sign::Pos) => Ordering::Less,
+        };
+
+        Some(ord)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        if !self.numer.is_empty() {
+            self.sign = self.sign.reverse();
+        }
+
+        self
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    /// Adds fractions as `a/b + c/d = (a*d + c*b) / (b*d)`.
+    fn add(self, rhs: Self) -> Self::Output {
+        let lhs_cross = limbs_mul::<LIMBS_RADIX>(&self.numer, &rhs.denom);
+        let rhs_cross = limbs_mul::<LIMBS_RADIX>(&rhs.numer, &self.denom);
+        let denom = limbs_mul::<LIMBS_RADIX>(&self.denom, &rhs.denom);
+
+        if self.sign.eq(&rhs.sign) {
+            return Self::new(
+                self.sign,
+                limbs_add::<LIMBS_RADIX>(&lhs_cross, &rhs_cross),
+                denom,
+            );
+        }
+
+        match limbs_cmp(&lhs_cross, &rhs_cross) {
+            Ordering::Less => Self::new(
+                rhs.sign,
+                limbs_sub::<LIMBS_RADIX>(&rhs_cross, &lhs_cross),
+                denom,
+            ),
+            _ => Self::new(
+                self.sign,
+                limbs_sub::<LIMBS_RADIX>(&lhs_cross, &rhs_cross),
+                denom,
+            ),
+        }
+    }
+}
+
+impl AddAssign for Rational {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone().add(rhs);
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.add(rhs.neg())
+    }
+}
+
+impl SubAssign for Rational {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone().sub(rhs);
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        let sign = match self.sign.eq(&rhs.sign) {
+            true => Sign::Pos,
+            false => Sign::Neg,
+        };
+
+        Self::new(
+            sign,
+            limbs_mul::<LIMBS_RADIX>(&self.numer, &rhs.numer),
+            limbs_mul::<LIMBS_RADIX>(&self.denom, &rhs.denom),
+        )
+    }
+}
+
+impl MulAssign for Rational {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone().mul(rhs);
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    /// Multiplies number by reciprocal of `rhs`, so division by zero
+    /// is infinity and zero divided by zero is NaN.
+    fn div(self, rhs: Self) -> Self::Output {
+        let recip = Self {
+            sign: rhs.sign,
+            numer: rhs.denom,
+            denom: rhs.numer,
+        };
+
+        self.mul(recip)
+    }
+}
+
+impl DivAssign for Rational {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone().div(rhs);
+    }
+}
+
+impl Rem for Rational {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl RemAssign for Rational {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.clone().rem(rhs);
+    }
+}
@@ -0,0 +1,37 @@
+use std::cell::Cell;
+
+/// Rule of rounding numbers that do not fit precision.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Rounds to nearest, ties to even (banker's rounding).
+    #[default]
+    HalfEven,
+    /// Rounds to nearest, ties away from zero.
+    HalfUp,
+    /// Rounds to nearest, ties toward zero.
+    HalfDown,
+    /// Truncates.
+    TowardZero,
+    /// Rounds toward negative infinity.
+    Floor,
+    /// Rounds toward positive infinity.
+    Ceiling,
+}
+
+thread_local! {
+    static CURRENT: Cell<RoundingMode> = Cell::new(RoundingMode::default());
+}
+
+impl RoundingMode {
+    /// Returns rounding mode of arithmetic in the current thread.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.get())
+    }
+
+    /// Sets rounding mode of arithmetic in the current thread.
+    ///
+    /// Returns the previous mode.
+    pub fn set_current(self) -> Self {
+        CURRENT.with(|current| current.replace(self))
+    }
+}
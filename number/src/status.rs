@@ -0,0 +1,141 @@
+use std::cell::Cell;
+use std::ops::BitOr;
+
+/// Exception flags raised by arithmetic.
+///
+/// Flags are raised by rounding results to precision of numbers and stay raised
+/// until they are cleared, so the status of the current thread describes
+/// all computations since the last clearing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Status {
+    inexact: bool,
+    overflow: bool,
+    underflow: bool,
+    div_by_zero: bool,
+    invalid: bool,
+}
+
+thread_local! {
+    static CURRENT: Cell<Status> = Cell::new(Status::default());
+}
+
+impl BitOr for Status {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            inexact: self.inexact || rhs.inexact,
+            overflow: self.overflow || rhs.overflow,
+            underflow: self.underflow || rhs.underflow,
+            div_by_zero: self.div_by_zero || rhs.div_by_zero,
+            invalid: self.invalid || rhs.invalid,
+        }
+    }
+}
+
+impl Status {
+    /// Result was rounded, it differs from the exact one.
+    pub fn inexact(&self) -> bool {
+        self.inexact
+    }
+
+    /// Result was too large by modulus and became infinity.
+    pub fn overflow(&self) -> bool {
+        self.overflow
+    }
+
+    /// Result was too small by modulus and lost precision.
+    pub fn underflow(&self) -> bool {
+        self.underflow
+    }
+
+    /// Finite number was divided by zero and became infinity.
+    pub fn div_by_zero(&self) -> bool {
+        self.div_by_zero
+    }
+
+    /// Operation has no meaningful result, e.g. `0/0` or `inf - inf`, and returned NaN.
+    pub fn invalid(&self) -> bool {
+        self.invalid
+    }
+
+    /// Checks if no flag is raised.
+    pub fn is_clear(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns flags raised in the current thread.
+    pub fn current() -> Self {
+        CURRENT.with(|current| current.get())
+    }
+
+    /// Sets flags of the current thread.
+    ///
+    /// Returns the previous flags.
+    pub fn set_current(self) -> Self {
+        CURRENT.with(|current| current.replace(self))
+    }
+
+    /// Clears flags of the current thread.
+    ///
+    /// Returns the cleared flags.
+    pub fn take() -> Self {
+        Self::default().set_current()
+    }
+
+    /// Runs computation with cleared flags, returns its result and flags it raised.
+    ///
+    /// The raised flags are kept in the current thread as well.
+    pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Self) {
+        let prev = Self::take();
+        let res = f();
+        let status = Self::current();
+        (prev | status).set_current();
+
+        (res, status)
+    }
+
+    /// Runs computation whose flags are discarded, e.g. rounding to integer,
+    /// which is exact by definition.
+    pub(crate) fn isolated<T>(f: impl FnOnce() -> T) -> T {
+        let prev = Self::current();
+        let res = f();
+        prev.set_current();
+
+        res
+    }
+
+    fn update(f: impl FnOnce(&mut Self)) {
+        let mut status = Self::current();
+        f(&mut status);
+        status.set_current();
+    }
+
+    pub(crate) fn raise_inexact() {
+        Self::update(|status| status.inexact = true);
+    }
+
+    /// Overflowed result is inexact as well.
+    pub(crate) fn raise_overflow() {
+        Self::update(|status| {
+            status.overflow = true;
+            status.inexact = true;
+        });
+    }
+
+    /// Underflow is raised only for inexact results.
+    pub(crate) fn raise_underflow() {
+        Self::update(|status| {
+            status.underflow = true;
+            status.inexact = true;
+        });
+    }
+
+    pub(crate) fn raise_div_by_zero() {
+        Self::update(|status| status.div_by_zero = true);
+    }
+
+    pub(crate) fn raise_invalid() {
+        Self::update(|status| status.invalid = true);
+    }
+}
@@ -0,0 +1,14 @@
+/// Number compared in total order of its `total_cmp`, so it can be sorted
+/// and used as key of maps and sets.
+///
+/// Unlike the number itself, wrapped NaN is equal to itself
+/// and wrapped zeros of different signs are not equal.
+#[derive(Clone, Debug, Default)]
+pub struct TotalOrder<T>(pub T);
+
+impl<T> TotalOrder<T> {
+    /// Returns wrapped number.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
@@ -0,0 +1,44 @@
+// Each test file uses only some of the fixtures
+#![allow(dead_code)]
+
+use number::{DynNumber, Number, NumberSpec, Rational};
+
+pub type Num = Number<10, 16>;
+
+/// Parses number literal or special value `NaN`, `inf` or `-inf`, panics on invalid literal.
+pub fn num_of<const RADIX: u32, const PREC: isize>(src: &str) -> Number<RADIX, PREC> {
+    match src {
+        "NaN" => Number::nan(),
+        "inf" => Number::inf(),
+        "-inf" => Number::neg_inf(),
+        _ => Number::try_from(src).unwrap_or_else(|err| panic!("{}: {}", src, err.to_string())),
+    }
+}
+
+pub fn num(src: &str) -> Num {
+    num_of(src)
+}
+
+/// Parses literal as `DynNumber` of given radix and precision.
+pub fn dyn_num_of(radix: u32, prec: isize, src: &str) -> DynNumber {
+    let prev = NumberSpec::new(radix, prec).unwrap().set_current();
+    let res = DynNumber::try_from(src).unwrap_or_else(|err| panic!("{}: {}", src, err));
+    prev.set_current();
+
+    res
+}
+
+/// Parses exact number, fraction `numer/denom` or special value as `num_of` does.
+pub fn rational(src: &str) -> Rational {
+    match src {
+        "NaN" => Rational::nan(),
+        "inf" => Rational::inf(),
+        "-inf" => -Rational::inf(),
+        _ => src
+            .split_once('/')
+            .map(|(numer, denom)| rational(numer) / rational(denom))
+            .unwrap_or_else(|| {
+                Rational::try_from(src).unwrap_or_else(|err| panic!("{}: {}", src, err.to_string()))
+            }),
+    }
+}
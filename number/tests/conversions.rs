@@ -0,0 +1,207 @@
+mod common;
+
+use common::*;
+use number::{
+    ArithmeticError, DynNumber, ExpRange, Number, RoundingMode, Status, TryFromNumberError,
+};
+
+#[test]
+fn from_int() {
+    let exact = |res: Result<Num, ArithmeticError>| res.map(|num| num.to_string());
+
+    assert_eq!(exact(Num::try_from(0u8)).as_deref(), Ok("0"));
+    assert_eq!(exact(Num::try_from(-128i8)).as_deref(), Ok("-128"));
+    assert_eq!(exact(Num::try_from(u16::MAX)).as_deref(), Ok("65535"));
+    assert_eq!(exact(Num::try_from(i32::MIN)).as_deref(), Ok("-2147483648"));
+    assert_eq!(
+        exact(Num::try_from(1234567890123456i64)).as_deref(),
+        Ok("1234567890123456")
+    );
+    assert_eq!(exact(Num::try_from(usize::MIN)).as_deref(), Ok("0"));
+    assert_eq!(exact(Num::try_from(-1isize)).as_deref(), Ok("-1"));
+
+    // Integers longer than precision are not rounded
+    assert_eq!(
+        Num::try_from(u64::MAX).err(),
+        Some(ArithmeticError::Inexact)
+    );
+    assert_eq!(
+        Num::try_from(12345678901234567i64).err(),
+        Some(ArithmeticError::Inexact)
+    );
+    assert_eq!(
+        exact(Num::try_from(10_000_000_000_000_000_000u128)).as_deref(),
+        Ok("1e19")
+    );
+    assert_eq!(
+        Number::<10, 40>::try_from(u128::MAX).map(|num| num.to_string()),
+        Ok("340282366920938463463374607431768211455".to_string())
+    );
+    assert_eq!(
+        Number::<16, 40>::try_from(i128::MIN).map(|num| num.to_string()),
+        Ok("-80000000000000000000000000000000".to_string())
+    );
+
+    let (_, status) = Status::capture(|| Num::try_from(u64::MAX));
+    assert!(status.is_clear());
+
+    let prev = ExpRange::new(-10, 10).unwrap().set_current();
+    assert_eq!(
+        Num::try_from(10u128.pow(20)).err(),
+        Some(ArithmeticError::Overflow)
+    );
+    prev.set_current();
+}
+
+#[test]
+fn to_int() {
+    let cases: [(&str, Result<i64, ArithmeticError>); 8] = [
+        ("42", Ok(42)),
+        ("-0", Ok(0)),
+        ("2.5", Ok(2)),
+        ("-3.5", Ok(-4)),
+        ("-1e15", Ok(-1_000_000_000_000_000)),
+        ("1e19", Err(ArithmeticError::Overflow)),
+        ("-inf", Err(ArithmeticError::Overflow)),
+        ("NaN", Err(ArithmeticError::Invalid)),
+    ];
+
+    for (src, expected) in cases {
+        let src_num = match src {
+            "-inf" => Num::neg_inf(),
+            "NaN" => Num::nan(),
+            _ => num(src),
+        };
+
+        assert_eq!(i64::try_from(src_num), expected, "{} to i64", src);
+    }
+
+    let max = Number::<10, 20>::try_from(i64::MAX).unwrap();
+    assert_eq!(i64::try_from(max.clone()), Ok(i64::MAX));
+    assert_eq!(
+        i64::try_from(max + Number::one()),
+        Err(ArithmeticError::Overflow)
+    );
+
+    assert_eq!(u8::try_from(num("255.4")), Ok(255));
+    assert_eq!(u8::try_from(num("255.5")), Err(ArithmeticError::Overflow));
+    assert_eq!(u32::try_from(num("-0.4")), Ok(0));
+    assert_eq!(u32::try_from(num("-1")), Err(ArithmeticError::Overflow));
+    assert_eq!(i8::try_from(num("-128")), Ok(-128));
+    assert_eq!(u128::try_from(num("1e30")), Ok(10u128.pow(30)));
+
+    let prev = RoundingMode::Floor.set_current();
+    assert_eq!(i32::try_from(num("-2.5")), Ok(-3));
+    assert_eq!(i32::try_from(num("2.5")), Ok(2));
+    prev.set_current();
+}
+
+#[test]
+fn from_f64() {
+    let cases = [
+        (0.1, "0.1"),
+        (-0.0, "-0"),
+        (1e300, "1e300"),
+        (5e-324, "5e-324"),
+        (0.1 + 0.2, "0.3"),
+        (f64::INFINITY, "inf"),
+        (f64::NAN, "NaN"),
+    ];
+
+    for (val, expected) in cases {
+        let res = Num::try_from(val).map(|num| num.to_string());
+        assert_eq!(res.as_deref(), Ok(expected), "{:e}", val);
+    }
+
+    let res = Number::<10, 20>::try_from(0.1 + 0.2).map(|num| num.to_string());
+    assert_eq!(res.as_deref(), Ok("0.30000000000000004"));
+
+    let res = Number::<2, 8>::try_from(0.75).map(|num| num.to_string());
+    assert_eq!(res.as_deref(), Ok("0.11"));
+
+    let prev = ExpRange::new(-10, 10).unwrap().set_current();
+    assert_eq!(Num::try_from(1e20).err(), Some(ArithmeticError::Overflow));
+    assert_eq!(Num::try_from(1e-30).err(), Some(ArithmeticError::Underflow));
+    prev.set_current();
+}
+
+#[test]
+fn to_f64() {
+    let cases = [
+        "0.1",
+        "-0",
+        "1.7976931348623157e308",
+        "1.7976931348623159e308",
+        "2.2250738585072011e-308",
+        "2.4703282292062327e-324",
+        "2.4703282292062328e-324",
+        "1e-400",
+        "123456789012345678901234567890",
+        "-9007199254740993",
+    ];
+
+    for src in cases {
+        let expected = src.parse::<f64>().unwrap();
+        let res = Number::<10, 40>::try_from(src).unwrap().to_f64();
+        assert_eq!(res.to_bits(), expected.to_bits(), "{}", src);
+    }
+
+    assert_eq!(Num::inf().to_f64(), f64::INFINITY);
+    assert!(Num::nan().to_f64().is_nan());
+
+    for val in [0.1, -1.5e-310, 6.02214076e23, f64::MAX, f64::MIN_POSITIVE] {
+        let res = Number::<2, 1100>::try_from(val).unwrap().to_f64();
+        assert_eq!(res.to_bits(), val.to_bits(), "{:e} in radix 2", val);
+
+        let res = Number::<3, 40>::try_from(val).unwrap().to_f64();
+        assert_eq!(res.to_bits(), val.to_bits(), "{:e} in radix 3", val);
+    }
+}
+
+#[test]
+fn sum_and_product() {
+    let nums = ["1.5", "2", "-3"].map(num);
+
+    assert_eq!(nums.iter().sum::<Num>().to_string(), "0.5");
+    assert_eq!(nums.iter().product::<Num>().to_string(), "-9");
+    assert_eq!(nums.into_iter().sum::<Num>().to_string(), "0.5");
+
+    assert_eq!(Vec::<Num>::new().into_iter().sum::<Num>().to_string(), "0");
+    assert_eq!(
+        Vec::<Num>::new().into_iter().product::<Num>().to_string(),
+        "1"
+    );
+}
+
+#[test]
+fn to_dyn_number() {
+    let dyn_num = DynNumber::try_from(num("-2.5")).unwrap();
+    assert_eq!((dyn_num.radix(), dyn_num.prec()), (10, 16));
+    assert_eq!(dyn_num.to_string(), "-2.5");
+
+    assert_eq!(
+        DynNumber::try_from(Number::<37, 16>::nan()).err(),
+        Some(TryFromNumberError::InadmissableRadix)
+    );
+    assert_eq!(
+        DynNumber::try_from(Number::<10, 0>::nan()).err(),
+        Some(TryFromNumberError::InvalidPrecision)
+    );
+    assert_eq!(
+        DynNumber::try_from(Number::<10, { isize::MIN }>::nan()).err(),
+        Some(TryFromNumberError::InvalidPrecision)
+    );
+}
+
+#[test]
+fn radix_prefixes() {
+    let hex = |src| num_of::<16, 16>(src).to_string();
+
+    assert_eq!(num("0b101").to_string(), "5");
+    assert_eq!(num("0x1e@-1").to_string(), "1.875");
+    assert_eq!(hex("0b1"), "b1");
+    assert_eq!(hex("0B1"), "b1");
+    assert_eq!(hex("0o17"), "f");
+    assert_eq!(hex("0x1e"), "1e");
+    assert_eq!(hex("2#101"), "5");
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use common::*;
+use number::{Number, Status};
+
+#[test]
+fn quotient_above_precision() {
+    let cases = [
+        ("1e100", "7", "1.428571428571429e99", "4"),
+        ("-1e100", "7", "-1.428571428571429e99", "-4"),
+        ("1e100", "-7", "-1.428571428571429e99", "4"),
+        ("1e20", "3", "3.333333333333333e19", "1"),
+        ("123456789012345678e3", "1e-3", "1.234567890123457e23", "0"),
+    ];
+
+    for (lhs, rhs, quotient, rem) in cases {
+        let (res_quotient, res_rem) = num(lhs).div_rem(num(rhs));
+
+        assert_eq!(
+            res_quotient.to_string(),
+            quotient,
+            "{} div_rem {}",
+            lhs,
+            rhs
+        );
+        assert_eq!(res_rem.to_string(), rem, "{} div_rem {}", lhs, rhs);
+    }
+}
+
+#[test]
+fn floor_division_above_precision() {
+    let cases = [
+        ("1e100", "7", "1.428571428571429e99", "4"),
+        ("-1e100", "7", "-1.428571428571429e99", "3"),
+        ("1e100", "-7", "-1.428571428571429e99", "-3"),
+        ("-1e100", "-7", "1.428571428571429e99", "-4"),
+    ];
+
+    for (lhs, rhs, quotient, rem) in cases {
+        let res_quotient = num(lhs).div_floor(num(rhs));
+        let res_rem = num(lhs).modulo(num(rhs));
+
+        assert_eq!(
+            res_quotient.to_string(),
+            quotient,
+            "{} div_floor {}",
+            lhs,
+            rhs
+        );
+        assert_eq!(res_rem.to_string(), rem, "{} modulo {}", lhs, rhs);
+    }
+}
+
+#[test]
+fn floor_quotient_is_rounded_once() {
+    type Short = Number<10, 4>;
+
+    // Truncated quotient -12345 rounds to -12340 and subtracting one keeps it,
+    // while floor quotient -12346 rounds to -12350
+    let res = Short::try_from("-12345.5")
+        .unwrap()
+        .div_floor(Short::try_from("1").unwrap());
+
+    assert_eq!(res.to_string(), "-1.235e4");
+}
+
+#[test]
+fn remainder_raises_no_flags_of_quotient() {
+    let (rem, status) = Status::capture(|| num("1e999999") % num("7"));
+
+    assert_eq!(rem.to_string(), "6");
+    assert!(!status.inexact());
+
+    let (rem, status) = Status::capture(|| num("1e100").modulo(num("-7")));
+
+    assert_eq!(rem.to_string(), "-3");
+    assert!(!status.inexact());
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use common::*;
+use number::NumberSpec;
+
+#[test]
+fn runtime_radix_and_precision() {
+    let cases = [
+        (dyn_num_of(16, 8, "ff") + dyn_num_of(16, 8, "1"), "100"),
+        (
+            dyn_num_of(16, 8, "1") / dyn_num_of(16, 8, "3"),
+            "0.55555555",
+        ),
+        (
+            dyn_num_of(2, 8, "1") / dyn_num_of(2, 8, "11"),
+            "0.010101011",
+        ),
+        (dyn_num_of(10, 4, "2") / dyn_num_of(10, 4, "3"), "0.6667"),
+    ];
+
+    for (res, expected) in cases {
+        assert_eq!(res.to_string(), expected);
+    }
+
+    let third = dyn_num_of(10, 250, "1") / dyn_num_of(10, 250, "3");
+    assert_eq!(third.prec(), 250);
+    assert_eq!(third.to_string(), format!("0.{}", "3".repeat(250)));
+}
+
+#[test]
+fn operands_of_different_specs() {
+    // Result has radix of the left operand and the greater precision
+    let res = dyn_num_of(10, 4, "1") / dyn_num_of(10, 8, "3");
+    assert_eq!((res.radix(), res.prec()), (10, 8));
+    assert_eq!(res.to_string(), "0.33333333");
+
+    let res = dyn_num_of(16, 8, "ff") + dyn_num_of(10, 8, "1");
+    assert_eq!((res.radix(), res.prec()), (16, 8));
+    assert_eq!(res.to_string(), "100");
+}
+
+#[test]
+fn conversions() {
+    let res = dyn_num_of(10, 8, "255").to_radix(16).unwrap();
+    assert_eq!((res.radix(), res.to_string()), (16, "ff".to_string()));
+    assert!(dyn_num_of(10, 8, "1").to_radix(37).is_none());
+
+    let res = dyn_num_of(16, 8, "0.8").to_number::<10, 16>();
+    assert_eq!(res.to_string(), "0.5");
+    let res = dyn_num_of(10, 8, "1.23456").to_number::<10, 4>();
+    assert_eq!(res.to_string(), "1.235");
+
+    assert_eq!(NumberSpec::new(1, 8), None);
+    assert_eq!(NumberSpec::new(37, 8), None);
+    assert_eq!(NumberSpec::new(10, 0), None);
+}
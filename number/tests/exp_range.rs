@@ -0,0 +1,57 @@
+mod common;
+
+use common::*;
+use number::ExpRange;
+
+#[test]
+fn precision_is_independent_of_magnitude() {
+    let cases = [
+        ("0.0000000000000000000000000000001", "1e-31"),
+        ("10000000000000000000000000000000000000000", "1e40"),
+        (
+            "123456789012345678901234567890e100",
+            "1.234567890123457e129",
+        ),
+        ("1.000000000000000000000000000001e-200", "1e-200"),
+    ];
+
+    for (src, expected) in cases {
+        assert_eq!(num(src).to_string(), expected, "{}", src);
+    }
+
+    assert_eq!((num("1e-500") * num("1e-400")).to_string(), "1e-900");
+    assert_eq!(
+        (num("3e500") / num("7e-500")).to_string(),
+        "4.285714285714286e999"
+    );
+    assert_eq!((num("1e40") + num("1")).to_string(), "1e40");
+}
+
+#[test]
+fn overflow_and_gradual_underflow() {
+    let prev = ExpRange::new(-10, 10).unwrap().set_current();
+
+    let cases = [
+        ("9e10", "*", "1", "90000000000"),
+        ("1e10", "*", "10", "inf"),
+        ("-1e10", "*", "10", "-inf"),
+        ("1e-10", "/", "10", "1e-11"),
+        ("1.234567890123456e-10", "/", "1000", "1.234567890123e-13"),
+        ("1e-25", "/", "2", "0"),
+        ("-1e-25", "/", "1.5", "-1e-25"),
+        ("-1e-25", "/", "3", "-0"),
+    ];
+
+    let results = cases.map(|(lhs, op, rhs, _)| match op {
+        "*" => (num(lhs) * num(rhs)).to_string(),
+        _ => (num(lhs) / num(rhs)).to_string(),
+    });
+    prev.set_current();
+
+    for ((lhs, op, rhs, expected), res) in cases.into_iter().zip(results) {
+        assert_eq!(res, expected, "{} {} {}", lhs, op, rhs);
+    }
+
+    assert_eq!(ExpRange::new(1, 10), None);
+    assert_eq!(ExpRange::new(-10, 10).unwrap().etiny(16), -25);
+}
@@ -0,0 +1,244 @@
+mod common;
+
+use common::*;
+use number::{Notation, Number, NumberFormat, RoundingMode, TrailingZeros};
+
+fn check_format(format: NumberFormat, cases: &[(&str, &str)]) {
+    for &(src, expected) in cases {
+        assert_eq!(
+            num(src).format(&format),
+            expected,
+            "{} with {:?}",
+            src,
+            format
+        );
+    }
+}
+
+#[test]
+fn auto_notation() {
+    check_format(
+        NumberFormat::new(),
+        &[
+            ("0", "0"),
+            ("-0", "-0"),
+            ("123.45", "123.45"),
+            ("-1.5", "-1.5"),
+            ("1e15", "1000000000000000"),
+            ("9999999999999999", "9999999999999999"),
+            ("1e16", "1e16"),
+            ("-1.25e20", "-1.25e20"),
+            ("0.000001", "0.000001"),
+            ("0.0000001", "1e-7"),
+            ("-1.5e-7", "-1.5e-7"),
+            ("NaN", "NaN"),
+            ("inf", "inf"),
+            ("-inf", "-inf"),
+        ],
+    );
+
+    assert_eq!(
+        num("1e16").to_string(),
+        num("1e16").format(&NumberFormat::new())
+    );
+}
+
+#[test]
+fn fixed_notation() {
+    let fixed = NumberFormat::new().with_notation(Notation::Fixed);
+
+    check_format(
+        fixed,
+        &[
+            ("1e16", "10000000000000000"),
+            ("1.5e-7", "0.00000015"),
+            ("-0", "-0"),
+        ],
+    );
+    check_format(
+        fixed.with_frac_digits(2),
+        &[
+            ("3.14159", "3.14"),
+            ("1.5", "1.50"),
+            ("2", "2.00"),
+            ("1.005", "1.00"),
+            ("1.015", "1.02"),
+            ("0.999", "1.00"),
+            ("-0", "-0.00"),
+            ("-0.001", "-0.00"),
+            ("inf", "inf"),
+        ],
+    );
+    check_format(
+        fixed.with_frac_digits(0),
+        &[("2.5", "2"), ("3.5", "4"), ("-2.5", "-2")],
+    );
+}
+
+#[test]
+fn frac_digits_round_with_current_mode() {
+    let format = NumberFormat::new()
+        .with_notation(Notation::Fixed)
+        .with_frac_digits(1);
+
+    let prev = RoundingMode::Floor.set_current();
+    check_format(format, &[("1.29", "1.2"), ("-1.21", "-1.3")]);
+
+    RoundingMode::HalfUp.set_current();
+    check_format(format, &[("1.25", "1.3"), ("-1.25", "-1.3")]);
+
+    prev.set_current();
+}
+
+#[test]
+fn trailing_zeros() {
+    let format = NumberFormat::new()
+        .with_notation(Notation::Fixed)
+        .with_frac_digits(3);
+
+    check_format(
+        format.with_trailing_zeros(TrailingZeros::Keep),
+        &[("1.5", "1.500"), ("2", "2.000"), ("1.23456", "1.235")],
+    );
+    check_format(
+        format.with_trailing_zeros(TrailingZeros::Trim),
+        &[
+            ("1.5", "1.5"),
+            ("2", "2"),
+            ("1.23456", "1.235"),
+            ("0.0001", "0"),
+            ("-0.0001", "-0"),
+        ],
+    );
+
+    // Without count of digits all the digits are shown and zeros are not added
+    check_format(
+        NumberFormat::new().with_trailing_zeros(TrailingZeros::Keep),
+        &[("1.50", "1.5"), ("100", "100")],
+    );
+}
+
+#[test]
+fn scientific_notation() {
+    let scientific = NumberFormat::new().with_notation(Notation::Scientific);
+
+    check_format(
+        scientific,
+        &[
+            ("1234.5", "1.2345e3"),
+            ("0.00012", "1.2e-4"),
+            ("1", "1e0"),
+            ("0", "0e0"),
+            ("-0", "-0e0"),
+        ],
+    );
+    check_format(
+        scientific.with_frac_digits(2),
+        &[
+            ("1234.5", "1.23e3"),
+            ("9.999", "1.00e1"),
+            ("-0.00012", "-1.20e-4"),
+            ("0", "0.00e0"),
+        ],
+    );
+}
+
+#[test]
+fn engineering_notation() {
+    let engineering = NumberFormat::new().with_notation(Notation::Engineering);
+
+    check_format(
+        engineering,
+        &[
+            ("1234.5", "1.2345e3"),
+            ("12345", "12.345e3"),
+            ("123456", "123.456e3"),
+            ("0.00012", "120e-6"),
+            ("0.5", "500e-3"),
+            ("-7", "-7e0"),
+            ("0", "0e0"),
+        ],
+    );
+    check_format(
+        engineering.with_frac_digits(1),
+        &[
+            ("12345", "12.3e3"),
+            ("999.96", "1.0e3"),
+            ("0.00012", "120.0e-6"),
+        ],
+    );
+}
+
+#[test]
+fn grouping() {
+    let grouped = NumberFormat::new().with_grouping(',');
+
+    check_format(
+        grouped,
+        &[
+            ("1234567.891", "1,234,567.891"),
+            ("-1234", "-1,234"),
+            ("123", "123"),
+            ("0.5", "0.5"),
+        ],
+    );
+    check_format(grouped.with_group_size(4), &[("1234567", "123,4567")]);
+    check_format(grouped.with_group_size(0), &[("1234567", "1234567")]);
+    check_format(
+        NumberFormat::new().with_group_size(2),
+        &[("1234567", "1234567")],
+    );
+    check_format(
+        grouped.with_notation(Notation::Engineering),
+        &[("123456", "123.456e3")],
+    );
+}
+
+#[test]
+fn exp_marker() {
+    let format = NumberFormat::new()
+        .with_notation(Notation::Scientific)
+        .with_exp_marker('E');
+
+    check_format(format, &[("1234.5", "1.2345E3"), ("-0.025", "-2.5E-2")]);
+    check_format(
+        format.with_notation(Notation::Fixed),
+        &[("1234.5", "1234.5")],
+    );
+}
+
+#[test]
+fn radix_of_digits() {
+    let format = NumberFormat::new().with_notation(Notation::Scientific);
+
+    let num = Number::<16, 16>::try_from("ff.8").unwrap();
+    assert_eq!(num.format(&format), "f.f8@1");
+    assert_eq!(num.format(&NumberFormat::new()), "ff.8");
+    assert_eq!(format!("{:e}", num), "f.f8@1");
+    assert_eq!(format!("{:E}", num), "f.f8@1");
+    assert_eq!(num.format(&format.with_exp_marker('p')), "f.f8p1");
+
+    let num = Number::<15, 16>::try_from("1@-8").unwrap();
+    assert_eq!(num.format(&NumberFormat::new()), "1@-8");
+    assert_eq!(Number::<15, 16>::try_from("1e").unwrap().to_string(), "1e");
+
+    let num = Number::<14, 16>::try_from("1@-8").unwrap();
+    assert_eq!(num.format(&NumberFormat::new()), "1e-8");
+
+    let num = Number::<2, 16>::try_from("0.001").unwrap();
+    assert_eq!(num.format(&format), "1e-3");
+}
+
+#[test]
+fn std_format_flags() {
+    assert_eq!(format!("{}", num("1.5")), "1.5");
+    assert_eq!(format!("{:.3}", num("1.5")), "1.500");
+    assert_eq!(format!("{:.1}", num("1e16")), "10000000000000000.0");
+    assert_eq!(format!("{:e}", num("1234.5")), "1.2345e3");
+    assert_eq!(format!("{:.2E}", num("1234.5")), "1.23E3");
+    assert_eq!(format!("{:>8}", num("1.5")), "     1.5");
+    assert_eq!(format!("{:<6}|", num("-1.5")), "-1.5  |");
+    assert_eq!(format!("{:+}", num("1.5")), "+1.5");
+    assert_eq!(format!("{:08.2}", num("-1.5")), "-0001.50");
+    assert_eq!(format!("{:6}", num("NaN")), "   NaN");
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use common::*;
+
+fn parse_err(src: &str) -> Option<String> {
+    Num::try_from(src).err().map(|err| err.to_string())
+}
+
+#[test]
+fn scientific_notation() {
+    let cases = [
+        ("6.022e23", "6.022e23"),
+        ("1.6E-19", "1.6e-19"),
+        ("1e+5", "100000"),
+        ("-2.5e-3", "-0.0025"),
+        ("1.5e0", "1.5"),
+        ("0.001e3", "1"),
+        ("12e-1", "1.2"),
+        ("0e100", "0"),
+    ];
+
+    for (src, expected) in cases {
+        assert_eq!(num(src).to_string(), expected, "{}", src);
+    }
+}
+
+#[test]
+fn invalid_exponent() {
+    for src in ["1e", "1e-", "1e+-5", "1e5.5", "1ee5", "1e5e5"] {
+        assert_eq!(
+            parse_err(src).as_deref(),
+            Some("invalid exponent"),
+            "{}",
+            src
+        );
+    }
+}
+
+#[test]
+fn long_literals_are_rounded() {
+    let long_frac = format!("1.{}", "1".repeat(200));
+    let long_int = format!("{}5", "9".repeat(150));
+    let cases = [
+        (long_frac.as_str(), "1.111111111111111"),
+        (long_int.as_str(), "1e151"),
+        ("0.99999999999999999999", "1"),
+        ("1.23456789012345650000000000000001", "1.234567890123457"),
+    ];
+
+    for (src, expected) in cases {
+        assert_eq!(num(src).to_string(), expected, "{}", src);
+    }
+}
+
+#[test]
+fn precision_above_hundred_digits() {
+    let num = num_of::<10, 1000>;
+
+    let third = num("1") / num("3");
+    assert_eq!(third.to_string(), format!("0.{}", "3".repeat(1000)));
+
+    let literal = format!("0.{}", "7".repeat(1500));
+    assert_eq!(num(&literal).to_string(), format!("0.{}8", "7".repeat(999)));
+}
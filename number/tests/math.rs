@@ -0,0 +1,173 @@
+mod common;
+
+use common::{num, num_of, Num};
+use number::Number;
+
+#[test]
+fn ln_near_one() {
+    let cases = [
+        ("1", "0"),
+        (
+            "0.99999999999999999999999999999999999999990",
+            "-1.00000000000000000000000000000000000000005e-40",
+        ),
+        (
+            "0.99999999999999999999",
+            "-1.000000000000000000005000000000000000000033333333333333333333583e-20",
+        ),
+        (
+            "1.0000000000000000000000000000000000001",
+            "9.9999999999999999999999999999999999995e-38",
+        ),
+    ];
+
+    for (src, expected) in cases {
+        let res = num_of::<10, 64>(src).ln();
+        assert_eq!(res.to_string(), expected, "ln({})", src);
+    }
+
+    let cases = [
+        ("1.000000000000000000000000000001", "9.999999999999999999999999999995000000000000000000000000000003333333333333333333333333333330833333333e-31"),
+        ("1.000000000000000000000000000000000000000000000000000000000001", "9.999999999999999999999999999999999999999999999999999999999995e-61"),
+    ];
+
+    for (src, expected) in cases {
+        let res = num_of::<10, 100>(src).ln();
+        assert_eq!(res.to_string(), expected, "ln({})", src);
+    }
+}
+
+#[test]
+fn acosh_near_one() {
+    let cases = [
+        (
+            "1.0000000000000000000000000000000000000001",
+            "1.414213562373095048801688724209698078569660090263928297384606391e-20",
+        ),
+        (
+            "1.0000001",
+            "0.0004472135917731780606347319003615823036445960816551746616235747459",
+        ),
+        (
+            "1.5",
+            "0.9624236501192068949955178268487368462703686687713210393220363377",
+        ),
+        ("1", "0"),
+    ];
+
+    for (src, expected) in cases {
+        let res = num_of::<10, 64>(src).acosh();
+        assert_eq!(res.to_string(), expected, "acosh({})", src);
+    }
+}
+
+#[test]
+fn pow() {
+    let cases = [
+        ("2", "10", "1024"),
+        ("2", "-2", "0.25"),
+        ("-2", "3", "-8"),
+        ("-2", "-3", "-0.125"),
+        ("1.5", "2", "2.25"),
+        ("10", "400", "1e400"),
+        ("2", "0.5", "1.414213562373095"),
+        ("4", "0.5", "2"),
+        ("1.1", "100", "13780.61233982227"),
+        ("3", "-2.5", "0.06415002990995842"),
+        ("-8", "0.5", "NaN"),
+        ("0", "0", "1"),
+        ("0", "-1", "inf"),
+        ("-0", "-1", "-inf"),
+        ("-0", "3", "-0"),
+    ];
+
+    for (base, exp, expected) in cases {
+        let res = num(base).pow(num(exp));
+        assert_eq!(res.to_string(), expected, "{}^{}", base, exp);
+    }
+}
+
+#[test]
+fn sqrt() {
+    let cases = [
+        ("4", "2"),
+        ("2", "1.414213562373095"),
+        ("0.00001", "0.003162277660168379"),
+        ("1e100", "1e50"),
+        ("-0", "-0"),
+        ("-1", "NaN"),
+        ("inf", "inf"),
+        ("-inf", "NaN"),
+    ];
+
+    for (src, expected) in cases {
+        assert_eq!(num(src).sqrt().to_string(), expected, "sqrt({})", src);
+    }
+}
+
+#[test]
+fn nth_root() {
+    let cases = [
+        ("27", "3", "3"),
+        ("-8", "3", "-2"),
+        ("10", "3", "2.154434690031884"),
+        ("2", "7", "1.104089513673812"),
+        ("1e100", "4", "1e25"),
+        ("8", "-3", "0.5"),
+        ("16", "0.5", "256"),
+        ("-16", "2", "NaN"),
+        ("-0", "3", "-0"),
+        ("0", "-2", "inf"),
+        ("inf", "3", "inf"),
+        ("-inf", "3", "-inf"),
+        ("2", "0", "NaN"),
+    ];
+
+    for (src, n, expected) in cases {
+        let res = num(src).nth_root(num(n));
+        assert_eq!(res.to_string(), expected, "root({}, {})", src, n);
+    }
+}
+
+#[test]
+fn constants() {
+    type Num50 = Number<10, 50>;
+    type Hex = Number<16, 16>;
+
+    let cases = [
+        (
+            Num50::pi(),
+            "3.1415926535897932384626433832795028841971693993751",
+        ),
+        (
+            Num50::tau(),
+            "6.2831853071795864769252867665590057683943387987502",
+        ),
+        (
+            Num50::e(),
+            "2.7182818284590452353602874713526624977572470937",
+        ),
+        (
+            Num50::phi(),
+            "1.6180339887498948482045868343656381177203091798058",
+        ),
+    ];
+
+    for (res, expected) in cases {
+        assert_eq!(res.to_string(), expected);
+    }
+
+    // Constants of lower precision are rounded from the cached more precise ones
+    let cases = [
+        (Num::pi(), "3.141592653589793"),
+        (Num::tau(), "6.283185307179586"),
+        (Num::e(), "2.718281828459045"),
+        (Num::phi(), "1.618033988749895"),
+    ];
+
+    for (res, expected) in cases {
+        assert_eq!(res.to_string(), expected);
+    }
+
+    assert_eq!(Hex::pi().to_string(), "3.243f6a8885a308d");
+}
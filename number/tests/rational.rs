@@ -0,0 +1,75 @@
+mod common;
+
+use common::rational;
+use number::Rational;
+
+fn check<F: Fn(Rational, Rational) -> Option<Rational>>(
+    name: &str,
+    op: F,
+    cases: &[(&str, &str, Option<&str>)],
+) {
+    for &(lhs, rhs, expected) in cases {
+        let res = op(rational(lhs), rational(rhs)).map(|res| res.to_string());
+        assert_eq!(res.as_deref(), expected, "{}({}, {})", name, lhs, rhs);
+    }
+}
+
+#[test]
+fn nth_root() {
+    let cases = [
+        ("4", "2", Some("2")),
+        ("2", "2", None),
+        ("27/8", "3", Some("3/2")),
+        ("27/7", "3", None),
+        ("-8", "3", Some("-2")),
+        ("-4", "2", Some("NaN")),
+        ("1/16", "-4", Some("2")),
+        ("1000000000000000000000000000000", "10", Some("1000")),
+        ("1000000000000000000000000000001", "10", None),
+        ("8", "3/2", Some("4")),
+        ("0", "5", Some("0")),
+        ("inf", "2", Some("inf")),
+        ("4", "0", Some("NaN")),
+        ("4", "inf", Some("NaN")),
+        ("2", "1000", None),
+        ("1", "1000000000000000000000", Some("1")),
+    ];
+
+    check("nth_root", Rational::nth_root, &cases);
+}
+
+#[test]
+fn sqrt() {
+    let cases = [
+        ("0", Some("0")),
+        ("1", Some("1")),
+        ("4/9", Some("2/3")),
+        ("2", None),
+        (
+            "152415787532388367504942236884722755800955129",
+            Some("12345678901234567890123"),
+        ),
+        ("-1", Some("NaN")),
+    ];
+
+    for (src, expected) in cases {
+        let res = rational(src).sqrt().map(|res| res.to_string());
+        assert_eq!(res.as_deref(), expected, "sqrt({})", src);
+    }
+}
+
+#[test]
+fn pow_ratio() {
+    let cases = [
+        ("4", "1/2", Some("2")),
+        ("4/9", "-3/2", Some("27/8")),
+        ("8", "2/3", Some("4")),
+        ("2", "1/2", None),
+        ("-8", "1/3", Some("NaN")),
+        ("2", "10", Some("1024")),
+        ("2", "inf", None),
+        ("NaN", "1/2", Some("NaN")),
+    ];
+
+    check("pow_ratio", Rational::pow_ratio, &cases);
+}
@@ -0,0 +1,171 @@
+mod common;
+
+use common::*;
+use number::RoundingMode;
+
+type Constant = fn() -> Num;
+
+const MODES: [RoundingMode; 6] = [
+    RoundingMode::HalfEven,
+    RoundingMode::HalfUp,
+    RoundingMode::HalfDown,
+    RoundingMode::TowardZero,
+    RoundingMode::Floor,
+    RoundingMode::Ceiling,
+];
+
+#[test]
+fn round_to() {
+    // Results in order of `MODES`
+    let cases = [
+        ("2.5", 0, ["2", "3", "2", "2", "2", "3"]),
+        ("3.5", 0, ["4", "4", "3", "3", "3", "4"]),
+        ("-2.5", 0, ["-2", "-3", "-2", "-2", "-3", "-2"]),
+        ("2.51", 0, ["3", "3", "3", "2", "2", "3"]),
+        ("-2.49", 0, ["-2", "-2", "-2", "-2", "-3", "-2"]),
+        ("1.005", 2, ["1", "1.01", "1", "1", "1", "1.01"]),
+        (
+            "-1.235",
+            2,
+            ["-1.24", "-1.24", "-1.23", "-1.23", "-1.24", "-1.23"],
+        ),
+        ("1250", -2, ["1200", "1300", "1200", "1200", "1200", "1300"]),
+        ("0.4", 0, ["0", "0", "0", "0", "0", "1"]),
+        ("-0.4", 0, ["-0", "-0", "-0", "-0", "-1", "-0"]),
+        ("7", 0, ["7", "7", "7", "7", "7", "7"]),
+    ];
+
+    for (src, digits, expected) in cases {
+        for (mode, expected) in MODES.into_iter().zip(expected) {
+            let res = num(src).round_to(digits, mode);
+            assert_eq!(
+                res.to_string(),
+                expected,
+                "{} to {} with {:?}",
+                src,
+                digits,
+                mode
+            );
+        }
+    }
+
+    for src in ["NaN", "inf", "-inf"] {
+        assert_eq!(num(src).round_to(0, RoundingMode::Ceiling).to_string(), src);
+    }
+}
+
+#[test]
+fn arithmetic_rounds_with_current_mode() {
+    // Results in order of `MODES`
+    let cases = [
+        (
+            "2",
+            "3",
+            [
+                "0.6666666666666667",
+                "0.6666666666666667",
+                "0.6666666666666667",
+                "0.6666666666666666",
+                "0.6666666666666666",
+                "0.6666666666666667",
+            ],
+        ),
+        (
+            "-1",
+            "3",
+            [
+                "-0.3333333333333333",
+                "-0.3333333333333333",
+                "-0.3333333333333333",
+                "-0.3333333333333333",
+                "-0.3333333333333334",
+                "-0.3333333333333333",
+            ],
+        ),
+        (
+            "1",
+            "32",
+            [
+                "0.03125", "0.03125", "0.03125", "0.03125", "0.03125", "0.03125",
+            ],
+        ),
+    ];
+
+    for (lhs, rhs, expected) in cases {
+        for (mode, expected) in MODES.into_iter().zip(expected) {
+            let prev = mode.set_current();
+            let res = num(lhs) / num(rhs);
+            prev.set_current();
+
+            assert_eq!(
+                res.to_string(),
+                expected,
+                "{} / {} with {:?}",
+                lhs,
+                rhs,
+                mode
+            );
+        }
+    }
+
+    // Ties of sums are rounded as well, 16 digits are kept
+    let ties = [
+        "1000000000000000",
+        "1000000000000001",
+        "1000000000000000",
+        "1000000000000000",
+        "1000000000000000",
+        "1000000000000001",
+    ];
+
+    for (mode, expected) in MODES.into_iter().zip(ties) {
+        let prev = mode.set_current();
+        let res = num("1000000000000000") + num("0.5");
+        prev.set_current();
+
+        assert_eq!(res.to_string(), expected, "sum with {:?}", mode);
+    }
+
+    assert_eq!(RoundingMode::current(), RoundingMode::HalfEven);
+}
+
+#[test]
+fn constants_round_with_current_mode() {
+    // Results in order of `MODES`
+    let cases: [(Constant, &str, [&str; 6]); 2] = [
+        (
+            Num::pi,
+            "pi",
+            [
+                "3.141592653589793",
+                "3.141592653589793",
+                "3.141592653589793",
+                "3.141592653589793",
+                "3.141592653589793",
+                "3.141592653589794",
+            ],
+        ),
+        (
+            Num::e,
+            "e",
+            [
+                "2.718281828459045",
+                "2.718281828459045",
+                "2.718281828459045",
+                "2.718281828459045",
+                "2.718281828459045",
+                "2.718281828459046",
+            ],
+        ),
+    ];
+
+    for (constant, name, expected) in cases {
+        for (mode, expected) in MODES.into_iter().zip(expected) {
+            let prev = mode.set_current();
+            let res = constant();
+            prev.set_current();
+
+            assert_eq!(res.to_string(), expected, "{} with {:?}", name, mode);
+        }
+    }
+}
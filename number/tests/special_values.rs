@@ -0,0 +1,483 @@
+mod common;
+
+use common::*;
+use number::{DynNumber, RoundingMode, TotalOrder};
+use std::cmp::Ordering;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashSet};
+use std::hash::{Hash, Hasher};
+
+type UnOp = fn(Num) -> Num;
+type BinOp = fn(Num, Num) -> Num;
+
+fn check_bin_op(name: &str, op: BinOp, cases: &[(&str, &str, &str)]) {
+    for &(lhs, rhs, expected) in cases {
+        let res = op(num(lhs), num(rhs)).to_string();
+        assert_eq!(res, expected, "{} {} {}", lhs, name, rhs);
+    }
+}
+
+fn check_un_op(name: &str, op: UnOp, cases: &[(&str, &str)]) {
+    for &(operand, expected) in cases {
+        let res = op(num(operand)).to_string();
+        assert_eq!(res, expected, "{}({})", name, operand);
+    }
+}
+
+#[test]
+fn add() {
+    check_bin_op(
+        "+",
+        |lhs, rhs| lhs + rhs,
+        &[
+            ("0", "0", "0"),
+            ("-0", "-0", "-0"),
+            ("0", "-0", "0"),
+            ("-0", "0", "0"),
+            ("1", "-1", "0"),
+            ("-1", "1", "0"),
+            ("-0", "1", "1"),
+            ("-1", "0", "-1"),
+            ("inf", "1", "inf"),
+            ("-inf", "-inf", "-inf"),
+            ("inf", "-inf", "NaN"),
+            ("-inf", "inf", "NaN"),
+            ("NaN", "1", "NaN"),
+            ("1", "NaN", "NaN"),
+            ("NaN", "inf", "NaN"),
+        ],
+    );
+}
+
+#[test]
+fn add_rounding_to_floor() {
+    let prev = RoundingMode::Floor.set_current();
+
+    check_bin_op(
+        "+",
+        |lhs, rhs| lhs + rhs,
+        &[
+            ("1", "-1", "-0"),
+            ("0", "-0", "-0"),
+            ("-0", "0", "-0"),
+            ("0", "0", "0"),
+            ("-0", "-0", "-0"),
+        ],
+    );
+
+    prev.set_current();
+}
+
+#[test]
+fn sub() {
+    check_bin_op(
+        "-",
+        |lhs, rhs| lhs - rhs,
+        &[
+            ("0", "0", "0"),
+            ("-0", "0", "-0"),
+            ("0", "-0", "0"),
+            ("-0", "-0", "0"),
+            ("1", "1", "0"),
+            ("inf", "inf", "NaN"),
+            ("-inf", "inf", "-inf"),
+            ("NaN", "NaN", "NaN"),
+        ],
+    );
+}
+
+#[test]
+fn mul() {
+    check_bin_op(
+        "*",
+        |lhs, rhs| lhs * rhs,
+        &[
+            ("2", "3", "6"),
+            ("0", "-1", "-0"),
+            ("-0", "-1", "0"),
+            ("-0", "0", "-0"),
+            ("-0", "-0", "0"),
+            ("inf", "-2", "-inf"),
+            ("-inf", "-inf", "inf"),
+            ("inf", "0", "NaN"),
+            ("0", "-inf", "NaN"),
+            ("-0", "inf", "NaN"),
+            ("NaN", "1", "NaN"),
+            ("1", "NaN", "NaN"),
+            ("NaN", "-0", "NaN"),
+            ("NaN", "NaN", "NaN"),
+        ],
+    );
+}
+
+#[test]
+fn div() {
+    check_bin_op(
+        "/",
+        |lhs, rhs| lhs / rhs,
+        &[
+            ("6", "3", "2"),
+            ("0", "1", "0"),
+            ("0", "-1", "-0"),
+            ("-0", "-1", "0"),
+            ("1", "0", "inf"),
+            ("1", "-0", "-inf"),
+            ("-1", "0", "-inf"),
+            ("-1", "-0", "inf"),
+            ("0", "0", "NaN"),
+            ("-0", "0", "NaN"),
+            ("1", "inf", "0"),
+            ("1", "-inf", "-0"),
+            ("-1", "inf", "-0"),
+            ("inf", "-2", "-inf"),
+            ("-inf", "-0", "inf"),
+            ("inf", "inf", "NaN"),
+            ("-inf", "inf", "NaN"),
+            ("NaN", "1", "NaN"),
+            ("1", "NaN", "NaN"),
+            ("NaN", "0", "NaN"),
+        ],
+    );
+}
+
+#[test]
+fn rem() {
+    check_bin_op(
+        "%",
+        |lhs, rhs| lhs % rhs,
+        &[
+            ("5", "3", "2"),
+            ("-5", "3", "-2"),
+            ("4", "-2", "0"),
+            ("-4", "2", "-0"),
+            ("-0", "1", "-0"),
+            ("0", "-1", "0"),
+            ("1", "inf", "1"),
+            ("-1", "-inf", "-1"),
+            ("-0", "inf", "-0"),
+            ("1", "0", "NaN"),
+            ("0", "-0", "NaN"),
+            ("inf", "1", "NaN"),
+            ("NaN", "1", "NaN"),
+            ("1", "NaN", "NaN"),
+        ],
+    );
+}
+
+#[test]
+fn pow_overflow_keeps_sign() {
+    check_bin_op(
+        "^",
+        Num::pow,
+        &[
+            ("-2", "999999999999999", "-inf"),
+            ("-2", "999999999999998", "inf"),
+            ("-1.0000001", "1000000000000001", "-inf"),
+            ("-0.5", "-999999999999999", "-inf"),
+            ("2", "999999999999999", "inf"),
+            ("-2", "-999999999999999", "-0"),
+        ],
+    );
+}
+
+#[test]
+fn neg_and_abs() {
+    check_un_op(
+        "neg",
+        |num| -num,
+        &[
+            ("0", "-0"),
+            ("-0", "0"),
+            ("inf", "-inf"),
+            ("-inf", "inf"),
+            ("NaN", "NaN"),
+        ],
+    );
+
+    check_un_op(
+        "abs",
+        Num::abs,
+        &[("-0", "0"), ("-1", "1"), ("-inf", "inf"), ("NaN", "NaN")],
+    );
+}
+
+#[test]
+fn rounding_keeps_sign_of_zero() {
+    check_un_op(
+        "trunc",
+        Num::trunc,
+        &[("-0.5", "-0"), ("-0", "-0"), ("0.5", "0"), ("NaN", "NaN")],
+    );
+    check_un_op(
+        "floor",
+        Num::floor,
+        &[("-0", "-0"), ("0.5", "0"), ("-inf", "-inf")],
+    );
+    check_un_op(
+        "ceil",
+        Num::ceil,
+        &[("-0.5", "-0"), ("-0", "-0"), ("inf", "inf")],
+    );
+    check_un_op(
+        "round",
+        Num::round,
+        &[("-0.4", "-0"), ("0.4", "0"), ("NaN", "NaN")],
+    );
+}
+
+#[test]
+fn sign() {
+    let cases = [
+        ("0", false, true),
+        ("-0", true, false),
+        ("1", false, true),
+        ("-1", true, false),
+        ("inf", false, true),
+        ("-inf", true, false),
+        ("NaN", false, false),
+    ];
+
+    for (src, is_neg, is_pos) in cases {
+        assert_eq!(num(src).is_sign_negative(), is_neg, "{} is negative", src);
+        assert_eq!(num(src).is_sign_positive(), is_pos, "{} is positive", src);
+    }
+}
+
+#[test]
+fn cmp() {
+    let cases = [
+        ("0", "-0", Some(Ordering::Equal)),
+        ("-0", "0", Some(Ordering::Equal)),
+        ("-0", "-1", Some(Ordering::Greater)),
+        ("1", "2", Some(Ordering::Less)),
+        ("1", "1.000", Some(Ordering::Equal)),
+        ("-inf", "-0", Some(Ordering::Less)),
+        ("inf", "inf", Some(Ordering::Equal)),
+        ("-inf", "-inf", Some(Ordering::Equal)),
+        ("inf", "1", Some(Ordering::Greater)),
+        ("NaN", "NaN", None),
+        ("NaN", "1", None),
+        ("1", "NaN", None),
+        ("NaN", "inf", None),
+    ];
+
+    for (lhs, rhs, ord) in cases {
+        let (lhs_num, rhs_num) = (num(lhs), num(rhs));
+
+        assert_eq!(lhs_num.partial_cmp(&rhs_num), ord, "{} cmp {}", lhs, rhs);
+        assert_eq!(
+            lhs_num == rhs_num,
+            ord == Some(Ordering::Equal),
+            "{} == {}",
+            lhs,
+            rhs
+        );
+        assert_eq!(
+            lhs_num != rhs_num,
+            ord != Some(Ordering::Equal),
+            "{} != {}",
+            lhs,
+            rhs
+        );
+        assert_eq!(
+            lhs_num < rhs_num,
+            ord == Some(Ordering::Less),
+            "{} < {}",
+            lhs,
+            rhs
+        );
+        assert_eq!(
+            lhs_num > rhs_num,
+            ord == Some(Ordering::Greater),
+            "{} > {}",
+            lhs,
+            rhs
+        );
+    }
+}
+
+#[test]
+fn nan_propagates_through_functions() {
+    let un_ops: [(&str, UnOp); 12] = [
+        ("sqrt", Num::sqrt),
+        ("exp", Num::exp),
+        ("ln", Num::ln),
+        ("log10", Num::log10),
+        ("sin", Num::sin),
+        ("cos", Num::cos),
+        ("tan", Num::tan),
+        ("asin", Num::asin),
+        ("atan", Num::atan),
+        ("sinh", Num::sinh),
+        ("tanh", Num::tanh),
+        ("floor", Num::floor),
+    ];
+
+    for (name, op) in un_ops {
+        check_un_op(name, op, &[("NaN", "NaN")]);
+    }
+
+    let bin_ops: [(&str, BinOp); 6] = [
+        ("pow", Num::pow),
+        ("log", Num::log),
+        ("nth_root", Num::nth_root),
+        ("atan2", Num::atan2),
+        ("div_floor", Num::div_floor),
+        ("modulo", Num::modulo),
+    ];
+
+    for (name, op) in bin_ops {
+        check_bin_op(name, op, &[("NaN", "2", "NaN"), ("2", "NaN", "NaN")]);
+    }
+}
+
+#[test]
+fn functions_keep_sign_of_zero() {
+    check_un_op("sqrt", Num::sqrt, &[("-0", "-0"), ("0", "0")]);
+    check_un_op("sin", Num::sin, &[("-0", "-0")]);
+    check_un_op("tan", Num::tan, &[("-0", "-0")]);
+    check_un_op("atan", Num::atan, &[("-0", "-0")]);
+    check_un_op("sinh", Num::sinh, &[("-0", "-0")]);
+    check_un_op("tanh", Num::tanh, &[("-0", "-0")]);
+}
+
+#[test]
+fn total_cmp() {
+    let sorted = ["-inf", "-1e10", "-1", "-0", "0", "1e-10", "1", "inf", "NaN"];
+
+    for (lhs_idx, lhs) in sorted.iter().enumerate() {
+        for (rhs_idx, rhs) in sorted.iter().enumerate() {
+            let ord = num(lhs).total_cmp(&num(rhs));
+            assert_eq!(ord, lhs_idx.cmp(&rhs_idx), "{} total_cmp {}", lhs, rhs);
+        }
+    }
+
+    let mut nums = ["NaN", "1", "-0", "-inf", "0", "-1"].map(num).to_vec();
+    nums.sort_by(Num::total_cmp);
+    let nums = nums.iter().map(|num| num.to_string()).collect::<Vec<_>>();
+    assert_eq!(nums, ["-inf", "-1", "-0", "0", "1", "NaN"]);
+}
+
+#[test]
+fn numbers_in_total_order_are_keys_of_ordered_maps() {
+    let map = ["NaN", "1", "1.0", "-0", "0", "NaN"]
+        .map(|src| (TotalOrder(num(src)), src))
+        .into_iter()
+        .collect::<BTreeMap<_, _>>();
+
+    let keys = map.keys().map(|key| key.0.to_string()).collect::<Vec<_>>();
+    assert_eq!(keys, ["-0", "0", "1", "NaN"]);
+    assert_eq!(map.get(&TotalOrder(num("NaN"))), Some(&"NaN"));
+    assert_eq!(map.get(&TotalOrder(num("1.00"))), Some(&"1.0"));
+}
+
+fn hash_of(num: &Num) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    num.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn equal_numbers_have_equal_hashes() {
+    let cases = [
+        ("0", "-0"),
+        ("1", "1.000"),
+        ("100", "1e2"),
+        ("0.5", "5e-1"),
+        ("-2.50", "-2.5"),
+        ("inf", "inf"),
+        ("NaN", "NaN"),
+    ];
+
+    for (lhs, rhs) in cases {
+        assert_eq!(
+            hash_of(&num(lhs)),
+            hash_of(&num(rhs)),
+            "hash {} and {}",
+            lhs,
+            rhs
+        );
+    }
+
+    assert_eq!(num("10") / num("4"), num("2.5"));
+    assert_eq!(hash_of(&(num("10") / num("4"))), hash_of(&num("2.5")));
+
+    let set = ["1", "1.0", "-0", "0", "2", "inf", "-inf", "NaN", "NaN"]
+        .map(|src| TotalOrder(num(src)))
+        .into_iter()
+        .collect::<HashSet<_>>();
+    assert_eq!(set.len(), 7);
+    assert!(set.contains(&TotalOrder(num("NaN"))));
+    assert!(set.contains(&TotalOrder(num("-0"))));
+}
+
+fn dyn_num(radix: u32, src: &str) -> DynNumber {
+    dyn_num_of(radix, 16, src)
+}
+
+fn dyn_hash_of(num: &DynNumber) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    TotalOrder(num.clone()).hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn dyn_numbers_are_compared_across_radixes() {
+    let cases = [
+        ((10, "0.5"), (2, "0.1"), Ordering::Equal),
+        ((10, "0.1"), (2, "0.1"), Ordering::Less),
+        ((16, "ff"), (10, "255"), Ordering::Equal),
+        ((3, "0.1"), (10, "0.3333333333333333"), Ordering::Greater),
+        ((10, "-0"), (2, "0"), Ordering::Less),
+        ((10, "1"), (2, "1e1000"), Ordering::Less),
+    ];
+
+    for ((lhs_radix, lhs), (rhs_radix, rhs), ord) in cases {
+        let (lhs_num, rhs_num) = (dyn_num(lhs_radix, lhs), dyn_num(rhs_radix, rhs));
+
+        assert_eq!(lhs_num.total_cmp(&rhs_num), ord, "{} cmp {}", lhs, rhs);
+        assert_eq!(
+            rhs_num.total_cmp(&lhs_num),
+            ord.reverse(),
+            "{} cmp {}",
+            rhs,
+            lhs
+        );
+    }
+
+    assert_eq!(dyn_num(10, "0.5"), dyn_num(2, "0.1"));
+    assert_eq!(dyn_num(10, "-0"), dyn_num(2, "0"));
+    assert!(dyn_num(3, "0.1") > dyn_num(10, "0.3333333333333333"));
+
+    let nan = DynNumber::try_from(Num::nan()).unwrap();
+    assert_ne!(nan, nan.clone());
+    assert_eq!(nan.partial_cmp(&dyn_num(2, "1e1000")), None);
+    assert_eq!(TotalOrder(nan.clone()), TotalOrder(nan.clone()));
+    assert!(TotalOrder(nan) > TotalOrder(dyn_num(2, "1e1000")));
+}
+
+#[test]
+fn equal_dyn_numbers_have_equal_hashes() {
+    let cases = [
+        ((10, "0.5"), (10, "0.50")),
+        ((2, "0.1"), (2, "1e-1")),
+        ((16, "ff"), (16, "0ff.0")),
+    ];
+
+    for ((lhs_radix, lhs), (rhs_radix, rhs)) in cases {
+        let (lhs_num, rhs_num) = (dyn_num(lhs_radix, lhs), dyn_num(rhs_radix, rhs));
+        assert_eq!(dyn_hash_of(&lhs_num), dyn_hash_of(&rhs_num), "hash {}", lhs);
+    }
+
+    let set = [
+        (10, "0.5"),
+        (2, "0.1"),
+        (4, "0.2"),
+        (10, "0.50"),
+        (2, "1e-1"),
+    ]
+    .map(|(radix, src)| TotalOrder(dyn_num(radix, src)))
+    .into_iter()
+    .collect::<HashSet<_>>();
+    assert_eq!(set.len(), 3);
+}
@@ -0,0 +1,202 @@
+mod common;
+
+use common::*;
+use number::{ArithmeticError, Rational, Status};
+
+type UnOp = fn(Num) -> Num;
+type BinOp = fn(Num, Num) -> Num;
+type CheckedOp = fn(Num, Num) -> Result<Num, ArithmeticError>;
+type CheckedCase<'a> = (&'a str, &'a str, Result<&'a str, ArithmeticError>);
+
+/// Flags in order inexact, overflow, underflow, division by zero, invalid.
+fn flags(status: Status) -> [bool; 5] {
+    [
+        status.inexact(),
+        status.overflow(),
+        status.underflow(),
+        status.div_by_zero(),
+        status.invalid(),
+    ]
+}
+
+fn check_flags(name: &str, op: BinOp, cases: &[(&str, &str, [bool; 5])]) {
+    for &(lhs, rhs, expected) in cases {
+        let (lhs_num, rhs_num) = (num(lhs), num(rhs));
+        let (_, status) = Status::capture(|| op(lhs_num, rhs_num));
+
+        assert_eq!(flags(status), expected, "{} {} {}", lhs, name, rhs);
+    }
+}
+
+fn check_checked(name: &str, op: CheckedOp, cases: &[CheckedCase]) {
+    for &(lhs, rhs, expected) in cases {
+        let res = op(num(lhs), num(rhs)).map(|res| res.to_string());
+        assert_eq!(
+            res.as_deref().map_err(|err| *err),
+            expected,
+            "{} {} {}",
+            lhs,
+            name,
+            rhs
+        );
+    }
+}
+
+const CLEAR: [bool; 5] = [false; 5];
+const INEXACT: [bool; 5] = [true, false, false, false, false];
+const OVERFLOW: [bool; 5] = [true, true, false, false, false];
+const UNDERFLOW: [bool; 5] = [true, false, true, false, false];
+const DIV_BY_ZERO: [bool; 5] = [false, false, false, true, false];
+const INVALID: [bool; 5] = [false, false, false, false, true];
+
+#[test]
+fn arithmetic_flags() {
+    check_flags(
+        "+",
+        |lhs, rhs| lhs + rhs,
+        &[
+            ("1", "2", CLEAR),
+            ("1e20", "1", INEXACT),
+            ("9e999999", "9e999999", OVERFLOW),
+            ("inf", "1", CLEAR),
+            ("inf", "-inf", INVALID),
+            ("NaN", "1", CLEAR),
+        ],
+    );
+    check_flags(
+        "*",
+        |lhs, rhs| lhs * rhs,
+        &[
+            ("2", "3", CLEAR),
+            ("1e-999999", "1e-999999", UNDERFLOW),
+            ("inf", "0", INVALID),
+        ],
+    );
+    check_flags(
+        "/",
+        |lhs, rhs| lhs / rhs,
+        &[
+            ("1", "4", CLEAR),
+            ("1", "3", INEXACT),
+            ("1", "0", DIV_BY_ZERO),
+            ("0", "0", INVALID),
+            ("inf", "0", CLEAR),
+            ("inf", "inf", INVALID),
+        ],
+    );
+}
+
+#[test]
+fn function_flags() {
+    let cases: [(&str, UnOp, &str, [bool; 5]); 8] = [
+        ("sqrt", Num::sqrt, "4", CLEAR),
+        ("sqrt", Num::sqrt, "2", INEXACT),
+        ("sqrt", Num::sqrt, "-1", INVALID),
+        ("ln", Num::ln, "0", DIV_BY_ZERO),
+        ("ln", Num::ln, "-1", INVALID),
+        ("sin", Num::sin, "inf", INVALID),
+        ("asin", Num::asin, "2", INVALID),
+        ("round", Num::round, "2.5", CLEAR),
+    ];
+
+    for (name, op, operand, expected) in cases {
+        let operand_num = num(operand);
+        let (_, status) = Status::capture(|| op(operand_num));
+
+        assert_eq!(flags(status), expected, "{}({})", name, operand);
+    }
+}
+
+#[test]
+fn flags_are_sticky() {
+    let prev = Status::take();
+
+    let _ = num("1") / num("3");
+    let _ = num("1") + num("2");
+    assert_eq!(flags(Status::current()), INEXACT);
+
+    let (_, status) = Status::capture(|| num("1") / num("0"));
+    assert_eq!(flags(status), DIV_BY_ZERO);
+    assert_eq!(flags(Status::current()), [true, false, false, true, false]);
+
+    assert!(Status::take().inexact());
+    assert!(Status::current().is_clear());
+
+    prev.set_current();
+}
+
+#[test]
+fn checked_ops() {
+    check_checked(
+        "+",
+        Num::checked_add,
+        &[
+            ("1", "2", Ok("3")),
+            ("1e20", "1", Ok("1e20")),
+            ("9e999999", "9e999999", Err(ArithmeticError::Overflow)),
+            ("inf", "-inf", Err(ArithmeticError::Invalid)),
+            ("NaN", "1", Ok("NaN")),
+        ],
+    );
+    check_checked(
+        "-",
+        Num::checked_sub,
+        &[
+            ("1", "2", Ok("-1")),
+            ("inf", "inf", Err(ArithmeticError::Invalid)),
+        ],
+    );
+    check_checked(
+        "*",
+        Num::checked_mul,
+        &[
+            ("2", "3", Ok("6")),
+            ("1e-999999", "1e-999999", Err(ArithmeticError::Underflow)),
+            ("0", "inf", Err(ArithmeticError::Invalid)),
+        ],
+    );
+    check_checked(
+        "/",
+        Num::checked_div,
+        &[
+            ("1", "4", Ok("0.25")),
+            ("1", "0", Err(ArithmeticError::DivisionByZero)),
+            ("0", "0", Err(ArithmeticError::Invalid)),
+            ("inf", "2", Ok("inf")),
+        ],
+    );
+}
+
+#[test]
+fn literal_exponent_out_of_range() {
+    let cases = [
+        ("0b1e-99999999", "0", UNDERFLOW),
+        ("2#1e9999999", "inf", OVERFLOW),
+        ("-3#2e-9999999", "-0", UNDERFLOW),
+        ("0b1e-10", "0.0009765625", CLEAR),
+    ];
+
+    for (src, expected, expected_flags) in cases {
+        let (res, status) = Status::capture(|| num(src));
+
+        assert_eq!(res.to_string(), expected, "{}", src);
+        assert_eq!(flags(status), expected_flags, "{}", src);
+    }
+}
+
+#[test]
+fn rational_literal_exponent_out_of_range() {
+    let cases = [
+        ("1e999999999", Err(())),
+        ("1e99999999", Err(())),
+        ("-1e-99999999", Err(())),
+        ("0b1e-99999999", Err(())),
+        ("0e99999999", Ok("0")),
+        ("25e-3", Ok("1/40")),
+    ];
+
+    for (src, expected) in cases {
+        let res = Rational::try_from(src).map(|num| num.to_string());
+        assert_eq!(res.as_deref().map_err(|_| ()), expected, "{}", src);
+    }
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use common::num_of;
+use number::{AngleUnit, Number};
+
+type Num = Number<10, 30>;
+
+fn num(src: &str) -> Num {
+    num_of(src)
+}
+
+#[test]
+fn angles_near_multiples_of_quarter_turn() {
+    let pi = Num::pi();
+    let half_pi = Num::pi() / num("2");
+
+    let cases = [
+        (
+            "sin(pi)",
+            pi.clone().sin(),
+            "-4.97115802830600624894179025055e-31",
+        ),
+        (
+            "cos(pi/2)",
+            half_pi.cos(),
+            "-2.48557901415300312447089512528e-31",
+        ),
+        (
+            "cos(1.5707963267948966)",
+            num("1.5707963267948966").cos(),
+            "1.92313216916397514420985846997e-17",
+        ),
+        (
+            "tan(1.5707963267948966)",
+            num("1.5707963267948966").tan(),
+            "51998506188720270.6601947416612",
+        ),
+        (
+            "sin(6.283185307179586)",
+            num("6.283185307179586").sin(),
+            "-4.76925286766559005768394338799e-16",
+        ),
+        (
+            "sin(355)",
+            num("355").sin(),
+            "-0.0000301443533594884492143302800087",
+        ),
+        (
+            "cos(1e22)",
+            num("1e22").cos(),
+            "0.523214785395138945497594473385",
+        ),
+        (
+            "sin(-pi)",
+            (-pi).sin(),
+            "4.97115802830600624894179025055e-31",
+        ),
+    ];
+
+    for (name, res, expected) in cases {
+        assert_eq!(res.to_string(), expected, "{}", name);
+    }
+}
+
+#[test]
+fn exact_multiples_of_quarter_turn() {
+    let cases = [
+        ("cos(0)", num("0").cos(), "1"),
+        ("cos(-0)", (-num("0")).cos(), "1"),
+        ("sin(0)", num("0").sin(), "0"),
+        ("tan(0)", num("0").tan(), "0"),
+        ("cos(90°)", num("90").cos_in(AngleUnit::Degrees), "0"),
+        ("sin(90°)", num("90").sin_in(AngleUnit::Degrees), "1"),
+        ("sin(180°)", num("180").sin_in(AngleUnit::Degrees), "0"),
+        ("cos(180°)", num("180").cos_in(AngleUnit::Degrees), "-1"),
+        ("tan(180°)", num("180").tan_in(AngleUnit::Degrees), "0"),
+        ("sin(-270°)", num("-270").sin_in(AngleUnit::Degrees), "1"),
+        ("cos(720°)", num("720").cos_in(AngleUnit::Degrees), "1"),
+    ];
+
+    for (name, res, expected) in cases {
+        assert_eq!(res.to_string(), expected, "{}", name);
+    }
+}
+
+#[test]
+fn acos_near_one() {
+    type Num64 = Number<10, 64>;
+
+    let cases = [
+        (
+            "0.99999999999999999999999999999999999999990",
+            "1.414213562373095048801688724209698078569683660489967848968753085e-20",
+        ),
+        (
+            "0.9",
+            "0.4510268117962624325446446357943518262034225132842500281117904322",
+        ),
+        (
+            "0.4",
+            "1.159279480727408599846583794022415837242883564560527058703525515",
+        ),
+        ("1", "0"),
+    ];
+
+    for (src, expected) in cases {
+        let res = Num64::try_from(src).unwrap().acos();
+        assert_eq!(res.to_string(), expected, "acos({})", src);
+    }
+}
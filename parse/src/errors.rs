@@ -1,6 +1,7 @@
 pub const ERR__EMPTY_EXPR: &str = "Empty expession.";
 
 pub const ERR__MISSING_OPERATOR: &str = "Missing operator on number.";
+pub const ERR__MISSING_CONST_OPERATOR: &str = "Missing operator on constant.";
 
 pub const ERR__INVALID_RIGHT_OPERAND: &str = "Invalid right operand of operator.";
 pub const ERR__MISSING_RIGHT_OPERAND: &str = "Missing right operand of operator.";
@@ -15,8 +16,6 @@ pub const ERR__PARSING_ERR: &str = "Parsing error.";
 
 pub const ERR__MISSING_ARGS_BLOCK_START: &str =
     "Missing function arguments block start. Expected identificator.";
-pub const ERR__MISSING_ARGS_BLOCK_START_PAREN: &str =
-    "Missing function arguments block start. Expected open paren after identificator.";
 pub const ERR__MISSING_ARGS_BLOCK_END: &str =
     "Unclosed function arguments block. Expected close paren.";
 pub const ERR__MISSING_ARG: &str =
@@ -6,19 +6,53 @@ use std::str::Chars;
 const EOF_CHAR: char = '\0';
 
 pub fn is_lit(chr: char) -> bool {
-    chr == '*' || chr == '/' || chr == '+' || chr == '-' || chr == ','
+    chr == '*' || chr == '/' || chr == '%' || chr == '+' || chr == '-' || chr == '^' || chr == ','
 }
 
 pub fn is_delim(chr: char) -> bool {
     chr == '(' || chr == ')' || chr == '{' || chr == '}' || chr == '[' || chr == ']'
 }
 
+/// Numbers start with a decimal digit whatever radix they are read in,
+/// so `ff` is a name and hexadecimal literal is written as `0ff` or `16#ff`.
 pub fn is_number_start(chr: char) -> bool {
     chr.is_digit(10)
 }
 
 pub fn is_number(chr: char) -> bool {
-    chr.is_digit(10) || chr.is_ascii_alphabetic() || chr == '.'
+    chr.is_digit(10) || chr.is_ascii_alphabetic() || chr == '.' || chr == '#' || chr == '@'
+}
+
+pub fn is_exp_marker(chr: char) -> bool {
+    chr == 'e' || chr == 'E'
+}
+
+/// Exponent marker of literals in any radix, e.g. `0x1f@-5`.
+pub fn is_radix_exp_marker(chr: char) -> bool {
+    chr == '@'
+}
+
+pub fn is_exp_sign(chr: char) -> bool {
+    chr == '+' || chr == '-'
+}
+
+/// Returns radix of number literal given by prefix `0x`, `0o`, `0b` or `radix#`.
+///
+/// Literal without prefix is in `radix`. Letters of prefixes that are digits of `radix`
+/// start no prefix, e.g. `0b1` is a hexadecimal number if `radix` is 16.
+pub fn literal_radix(literal: &str, radix: u32) -> u32 {
+    let prefix = literal
+        .get(..2)
+        .filter(|prefix| !prefix.ends_with(|chr: char| chr.is_digit(radix)))
+        .unwrap_or_default();
+
+    match (prefix, literal.split_once('#')) {
+        ("0x" | "0X", _) => 16,
+        ("0o" | "0O", _) => 8,
+        ("0b" | "0B", _) => 2,
+        (_, Some((literal_radix, _))) => literal_radix.parse().unwrap_or(radix),
+        _ => radix,
+    }
 }
 
 pub fn is_ident_start(chr: char) -> bool {
@@ -53,9 +87,9 @@ pub fn is_whitespace(chr: char) -> bool {
     )
 }
 
+/// Symbols that start no token, e.g. `.` or `#` without digits before them.
 pub fn is_unknown(chr: char) -> bool {
     !(is_number_start(chr)
-        || is_number(chr)
         || is_ident_start(chr)
         || is_ident(chr)
         || is_lit(chr)
@@ -66,6 +100,8 @@ pub fn is_unknown(chr: char) -> bool {
 pub struct Lexer<'chars> {
     chars: Chars<'chars>,
     offset: usize,
+    /// Radix of literals without prefix, it decides which letters are digits.
+    radix: u32,
 }
 
 impl<'chars> Lexer<'chars> {
@@ -82,9 +118,16 @@ impl<'chars> Lexer<'chars> {
         Self {
             chars: expr.chars(),
             offset: 0,
+            radix: 10,
         }
     }
 
+    /// Sets radix of literals without prefix, 10 by default.
+    pub fn with_radix(mut self, radix: u32) -> Self {
+        self.radix = radix;
+        self
+    }
+
     fn is_eof(&self) -> bool {
         self.chars.as_str().is_empty()
     }
@@ -100,6 +143,12 @@ impl<'chars> Lexer<'chars> {
         SpanWrapper::new(val, span)
     }
 
+    fn second(&mut self) -> char {
+        let mut chars = self.chars.clone();
+        chars.next();
+        chars.next().unwrap_or(EOF_CHAR)
+    }
+
     fn cut<V: Clone + Debug>(&mut self, wrapper: &SpanWrapper<V>) {
         let end = wrapper.borrow_span().clone().end();
 
@@ -138,16 +187,20 @@ impl<'chars> Lexer<'chars> {
     }
 
     fn produce_lit(&mut self) -> Option<SpanWrapper<Token>> {
-        let lit_kind = match self.first().val() {
-            '*' => LitKind::Asterisk,
-            '/' => LitKind::Slash,
-            '+' => LitKind::Plus,
-            '-' => LitKind::Hyphen,
-            ',' => LitKind::Comma,
+        let (lit_kind, len) = match (self.first().val(), self.second()) {
+            ('/', '/') => (LitKind::DoubleSlash, 2),
+            ('*', _) => (LitKind::Asterisk, 1),
+            ('/', _) => (LitKind::Slash, 1),
+            ('%', _) => (LitKind::Percent, 1),
+            ('+', _) => (LitKind::Plus, 1),
+            ('-', _) => (LitKind::Hyphen, 1),
+            ('^', _) => (LitKind::Caret, 1),
+            (',', _) => (LitKind::Comma, 1),
             _ => return None,
         };
 
-        Some(SpanWrapper::new(Token::Lit(lit_kind), self.first().span()))
+        let span = Span::new(self.offset, self.offset + len);
+        Some(SpanWrapper::new(Token::Lit(lit_kind), span))
     }
 
     fn produce_delim(&mut self) -> Option<SpanWrapper<Token>> {
@@ -170,7 +223,22 @@ impl<'chars> Lexer<'chars> {
     }
 
     fn produce_num(&mut self) -> Option<SpanWrapper<Token>> {
-        self.consume_while(is_number_start, is_number)
+        let mut literal = String::from(self.first().val());
+        let radix = self.radix;
+
+        // Sign is a part of number only right after exponent marker, e.g. `1e-5`,
+        // marker `e` is a digit in radixes greater than 14, e.g. `0x1e-5`, unlike `@`
+        let is_number_tail = |chr| {
+            let is_exp = literal.ends_with(is_radix_exp_marker)
+                || literal.ends_with(|marker: char| {
+                    is_exp_marker(marker) && !marker.is_digit(literal_radix(&literal, radix))
+                });
+            let is_tail = is_number(chr) || (is_exp_sign(chr) && is_exp);
+            literal.push(chr);
+            is_tail
+        };
+
+        self.consume_while(is_number_start, is_number_tail)
             .map(|w| w.map(|v| Token::Num(v)))
     }
 
@@ -202,3 +270,156 @@ impl<'chars> Lexer<'chars> {
         tokens.into_iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tokens(expr: &str) -> Vec<Token> {
+        Lexer::new(expr)
+            .tokenize()
+            .map(|token| token.val())
+            .collect()
+    }
+
+    #[test]
+    fn point_without_int_part_is_unknown() {
+        assert_eq!(
+            tokens(".5"),
+            [Token::Unknown, Token::Num("5".to_string()), Token::Eof]
+        );
+        assert_eq!(
+            tokens("1+.5"),
+            [
+                Token::Num("1".to_string()),
+                Token::Lit(LitKind::Plus),
+                Token::Unknown,
+                Token::Num("5".to_string()),
+                Token::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_symbols() {
+        assert_eq!(tokens("@"), [Token::Unknown, Token::Eof]);
+        assert_eq!(
+            tokens("1 @. 2"),
+            [
+                Token::Num("1".to_string()),
+                Token::Whitespace,
+                Token::Unknown,
+                Token::Whitespace,
+                Token::Num("2".to_string()),
+                Token::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn hash_without_radix_is_unknown() {
+        assert_eq!(tokens("#"), [Token::Unknown, Token::Eof]);
+        assert_eq!(
+            tokens("1 # 2"),
+            [
+                Token::Num("1".to_string()),
+                Token::Whitespace,
+                Token::Unknown,
+                Token::Whitespace,
+                Token::Num("2".to_string()),
+                Token::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn prefixed_numbers() {
+        assert_eq!(
+            tokens("16#ff"),
+            [Token::Num("16#ff".to_string()), Token::Eof]
+        );
+        assert_eq!(
+            tokens("0x1e-5"),
+            [
+                Token::Num("0x1e".to_string()),
+                Token::Lit(LitKind::Hyphen),
+                Token::Num("5".to_string()),
+                Token::Eof,
+            ]
+        );
+        assert_eq!(
+            tokens("0x1e@-5"),
+            [Token::Num("0x1e@-5".to_string()), Token::Eof]
+        );
+        assert_eq!(tokens("1@+5"), [Token::Num("1@+5".to_string()), Token::Eof]);
+    }
+
+    #[test]
+    fn letters_are_digits_of_radix() {
+        let tokens = |expr| {
+            Lexer::new(expr)
+                .with_radix(16)
+                .tokenize()
+                .map(|token| token.val())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            tokens("1e-5"),
+            [
+                Token::Num("1e".to_string()),
+                Token::Lit(LitKind::Hyphen),
+                Token::Num("5".to_string()),
+                Token::Eof,
+            ]
+        );
+        assert_eq!(
+            tokens("0b1e-5"),
+            [
+                Token::Num("0b1e".to_string()),
+                Token::Lit(LitKind::Hyphen),
+                Token::Num("5".to_string()),
+                Token::Eof,
+            ]
+        );
+        assert_eq!(
+            tokens("0o1e-5"),
+            [Token::Num("0o1e-5".to_string()), Token::Eof]
+        );
+        assert_eq!(
+            tokens("10#1e-5"),
+            [Token::Num("10#1e-5".to_string()), Token::Eof]
+        );
+    }
+
+    #[test]
+    fn caret() {
+        assert_eq!(
+            tokens("2^-1"),
+            [
+                Token::Num("2".to_string()),
+                Token::Lit(LitKind::Caret),
+                Token::Lit(LitKind::Hyphen),
+                Token::Num("1".to_string()),
+                Token::Eof,
+            ]
+        );
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(
+            tokens("1.5e-3"),
+            [Token::Num("1.5e-3".to_string()), Token::Eof]
+        );
+        assert_eq!(
+            tokens("1.6E+19-2e3"),
+            [
+                Token::Num("1.6E+19".to_string()),
+                Token::Lit(LitKind::Hyphen),
+                Token::Num("2e3".to_string()),
+                Token::Eof,
+            ]
+        );
+    }
+}
@@ -28,8 +28,18 @@ use ir::span::SpanWrapper;
 use lexer::Lexer;
 use parser::Parser;
 
-pub fn parse(expr: &'static str) -> Result<Box<SpanWrapper<Node>>, SpanWrapper<String>> {
-    Ok(Box::new(SpanWrapper::new(Node::Num(String::from("sdf")), ir::span::Span::new(0, 0))))
+pub fn parse<E: AsRef<str>>(expr: E) -> Result<Box<SpanWrapper<Node>>, SpanWrapper<String>> {
+    parse_with_radix(expr, 10)
+}
+
+/// Parses expression whose literals without prefix are in `radix`,
+/// so letters that are its digits are parts of numbers, e.g. `e` in `1e-5` with radix 16.
+pub fn parse_with_radix<E: AsRef<str>>(
+    expr: E,
+    radix: u32,
+) -> Result<Box<SpanWrapper<Node>>, SpanWrapper<String>> {
+    let tokens = Lexer::new(expr.as_ref()).with_radix(radix).tokenize();
+    Parser::new(tokens).build_ast()
 }
 
 use std::fmt::Debug;
@@ -27,8 +27,9 @@ pub struct Parser {
 pub type PResult<O = ()> = Result<O, SpanWrapper<String>>;
 
 impl Parser {
-    const PARSERS: [fn(&mut Self) -> Result<(), SpanWrapper<String>>; 4] = [
+    const PARSERS: [fn(&mut Self) -> PResult<bool>; 5] = [
         Self::parse_fn,
+        Self::parse_const,
         Self::parse_delimited,
         Self::parse_num,
         Self::parse_op,
@@ -185,7 +186,7 @@ impl Parser {
     pub fn build_ast(mut self) -> Result<Box<SpanWrapper<Node>>, SpanWrapper<String>> {
         self.filter_whitespaces();
 
-        if self.is_eof() {
+        if matches!(self.first().val(), Token::Eof) {
             return Err(SpanWrapper::new(ERR__EMPTY_EXPR.to_owned(), self.expr_span));
         }
 
@@ -193,7 +194,9 @@ impl Parser {
             self.parse_unknown()?;
 
             for parse in Self::PARSERS {
-                parse(&mut self)?;
+                if parse(&mut self)? {
+                    break;
+                }
             }
             self.bump();
         }
@@ -0,0 +1,25 @@
+use super::errors::*;
+use super::parser::*;
+use ir::ast::*;
+use ir::span::*;
+use ir::token::*;
+
+impl Parser {
+    pub(crate) fn parse_const(&mut self) -> PResult<bool> {
+        let (token, span) = self.first().to_tuple();
+
+        let name = match token {
+            Token::Ident(name) => name,
+            _ => return Ok(false),
+        };
+
+        if !self.is_valid_operand_bounds() {
+            return Self::new_err(ERR__MISSING_CONST_OPERATOR, span);
+        }
+
+        let node = SpanWrapper::new(Node::Const(name), span);
+        self.push_node(node);
+
+        Ok(true)
+    }
+}
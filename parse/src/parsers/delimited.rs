@@ -7,12 +7,12 @@ use ir::token::*;
 
 
 impl Parser {
-    fn parse_delimited_start(&mut self) -> PResult {
+    fn parse_delimited_start(&mut self) -> PResult<bool> {
         let (delim_val, delim_span) = self.first().to_tuple();
 
         let delim_kind = match delim_val {
             Token::OpenDelim(delim_kind) => delim_kind,
-            _ => return Ok(()),
+            _ => return Ok(false),
         };
 
         matches_or!(
@@ -23,15 +23,15 @@ impl Parser {
         );
 
         self.push_buff_tuple(BuffElem::Delim(delim_kind), delim_span);
-        Ok(())
+        Ok(true)
     }
 
-    fn parse_delimited_end(&mut self) -> Result<(), SpanWrapper<String>> {
+    fn parse_delimited_end(&mut self) -> PResult<bool> {
         let (delim_val, delim_span) = self.first().to_tuple();
 
         let delim_kind = match delim_val {
             Token::CloseDelim(delim_kind) => delim_kind,
-            _ => return Ok(()),
+            _ => return Ok(false),
         };
 
         self.collect_ops()?;
@@ -42,14 +42,13 @@ impl Parser {
         matches_or_else!(
             required_delim_val,
             BuffElem::Delim(required_delim_kind) if delim_kind == required_delim_kind,
-            Ok(()),
+            Ok(true),
             ERR__UNOPENED_DELIMITED_BLOCK,
             delim_span
         )
     }
 
-    pub(crate) fn parse_delimited(&mut self) -> PResult {
-        self.parse_delimited_start()?;
-        self.parse_delimited_end()
+    pub(crate) fn parse_delimited(&mut self) -> PResult<bool> {
+        Ok(self.parse_delimited_start()? || self.parse_delimited_end()?)
     }
 }
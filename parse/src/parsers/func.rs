@@ -26,32 +26,28 @@ impl Parser {
         Ok(())
     }
 
-    fn parse_fn_name(&mut self) -> PResult {
+    fn parse_fn_name(&mut self) -> PResult<bool> {
         let (ident_val, ident_span) = self.first().to_tuple();
 
         let fn_name = match ident_val {
             Token::Ident(val) => val,
-            _ => return Ok(()),
+            _ => return Ok(false),
         };
 
-        matches_or!(
-            self.second().to_tuple(),
-            (Token::OpenDelim(DelimKind::Paren), _),
-            ERR__MISSING_ARGS_BLOCK_START_PAREN,
-            ident_span,
-        );
+        if !matches!(self.second().val(), Token::OpenDelim(DelimKind::Paren)) {
+            return Ok(false);
+        }
 
-        self.bump();
         self.bump();
 
         self.push_buff_tuple(BuffElem::Fn(fn_name, 0), ident_span);
-        Ok(())
+        Ok(true)
     }
 
-    fn parse_fn_arg_separator(&mut self) -> Result<(), SpanWrapper<String>> {
+    fn parse_fn_arg_separator(&mut self) -> PResult<bool> {
         let separator_span = match self.first().val() {
             Token::Lit(LitKind::Comma) => self.first().span(),
-            _ => return Ok(()),
+            _ => return Ok(false),
         };
 
         matches_or!(
@@ -80,15 +76,15 @@ impl Parser {
             [&separator_span, &Span::new(0, 0)].concat_span()
         );
 
-        Ok(())
+        Ok(true)
     }
 
-    fn parse_fn_end(&mut self) -> Result<(), SpanWrapper<String>> {
+    fn parse_fn_end(&mut self) -> PResult<bool> {
         let (delim_val, delim_span) = self.first().to_tuple();
 
         match delim_val {
             Token::CloseDelim(DelimKind::Paren) => (),
-            _ => return Ok(()),
+            _ => return Ok(false),
         };
 
         self.collect_ops()?;
@@ -96,21 +92,19 @@ impl Parser {
         match self.pop_buff_tuple() {
             Some((val, span)) => match val {
                 BuffElem::Fn(fn_name, args_count) => {
-                    self.bump();
-                    self.build_fn(fn_name, args_count + 1, [span, delim_span].concat_span())
+                    self.build_fn(fn_name, args_count + 1, [span, delim_span].concat_span())?;
+                    Ok(true)
                 }
                 buff_elem => {
                     self.push_buff_tuple(buff_elem, span);
-                    Ok(())
+                    Ok(false)
                 }
             },
-            _ => Ok(()),
+            _ => Ok(false),
         }
     }
 
-    pub(crate) fn parse_fn(&mut self) -> Result<(), SpanWrapper<String>> {
-        self.parse_fn_name()?;
-        self.parse_fn_arg_separator()?;
-        self.parse_fn_end()
+    pub(crate) fn parse_fn(&mut self) -> PResult<bool> {
+        Ok(self.parse_fn_name()? || self.parse_fn_arg_separator()? || self.parse_fn_end()?)
     }
 }
@@ -1,3 +1,4 @@
+mod constant;
 mod delimited;
 mod func;
 mod num;
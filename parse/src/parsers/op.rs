@@ -20,12 +20,15 @@ impl Parser {
     fn is_valid_right_operand(&self) -> bool {
         matches!(
             self.second().val(),
-            Token::OpenDelim(_) | Token::Num(_) | Token::Ident(_)
+            Token::OpenDelim(_) | Token::Num(_) | Token::Ident(_) | Token::Lit(LitKind::Hyphen)
         )
     }
 
     fn is_valid_left_operand(&self) -> bool {
-        matches!(self.curr().val(), Token::CloseDelim(_) | Token::Num(_))
+        matches!(
+            self.curr().val(),
+            Token::CloseDelim(_) | Token::Num(_) | Token::Ident(_)
+        )
     }
 
     fn build_bin_op(&mut self, kind: BinOpKind, span: Span) -> PResult {
@@ -75,6 +78,9 @@ impl Parser {
             Token::Lit(LitKind::Hyphen) => BinOpKind::Sub,
             Token::Lit(LitKind::Asterisk) => BinOpKind::Mul,
             Token::Lit(LitKind::Slash) => BinOpKind::Div,
+            Token::Lit(LitKind::DoubleSlash) => BinOpKind::FloorDiv,
+            Token::Lit(LitKind::Percent) => BinOpKind::Mod,
+            Token::Lit(LitKind::Caret) => BinOpKind::Pow,
             _ => return Ok(false),
         };
 
@@ -94,20 +100,29 @@ impl Parser {
             return Self::new_err(ERR__INVALID_LEFT_OPERAND, new_op_span);
         }
 
-        let (last_op_val, last_op_span) = match self.pop_buff_tuple() {
-            Some(t) => t,
-            None => {
-                self.push_buff_tuple(BuffElem::BinOp(new_op_kind), new_op_span);
-                return Ok(true);
-            }
-        };
+        loop {
+            let (last_op_val, last_op_span) = match self.pop_buff_tuple() {
+                Some(t) => t,
+                None => break,
+            };
 
-        match last_op_val {
-            BuffElem::BinOp(last_op_kind) if last_op_kind >= new_op_kind => {
-                self.build_bin_op(last_op_kind, last_op_span)?
+            match last_op_val {
+                BuffElem::BinOp(last_op_kind)
+                    if last_op_kind > new_op_kind
+                        || (last_op_kind == new_op_kind && !new_op_kind.is_right_assoc()) =>
+                {
+                    self.build_bin_op(last_op_kind, last_op_span)?
+                }
+                BuffElem::UnOp(last_op_kind)
+                    if last_op_kind.priority() >= new_op_kind.priority() =>
+                {
+                    self.build_un_op(last_op_kind, last_op_span)?
+                }
+                _ => {
+                    self.push_buff_tuple(last_op_val, last_op_span);
+                    break;
+                }
             }
-            BuffElem::UnOp(last_op_kind) => self.build_un_op(last_op_kind, last_op_span)?,
-            _ => self.push_buff_tuple(last_op_val, last_op_span),
         }
 
         self.push_buff_tuple(BuffElem::BinOp(new_op_kind), new_op_span);
@@ -134,15 +149,12 @@ impl Parser {
         Ok(true)
     }
 
-    pub(crate) fn parse_op(&mut self) -> PResult {
+    pub(crate) fn parse_op(&mut self) -> PResult<bool> {
         match self.parse_bin_op() {
-            Ok(true) => return Ok(()),
-            Ok(false) => match self.parse_un_op() {
-                Ok(_) => Ok(()),
-                Err(err) => Err(err),
-            },
+            Ok(true) => return Ok(true),
+            Ok(false) => self.parse_un_op(),
             Err(err) => match self.parse_un_op() {
-                Ok(true) => Ok(()),
+                Ok(true) => Ok(true),
                 Ok(false) => Err(err),
                 Err(err_2) => Err(err_2),
             },
@@ -1,23 +1,142 @@
+mod warnings;
+
+use interpreter::{
+    evaluate_approximately_with, evaluate_with, evaluate_with_status, AngleUnit, Config, DynNumber,
+    NumberSpec, Rational, RoundingMode, Status,
+};
 use notification::Notification;
-use ir::span::SpanWrapper;
-use interpreter;
-use parse::parse;
-
-fn main() {
-    dbg!(parse("11+342+4"));
-    /*
-    let expr = String::from("((- (2 + (0 + 0))) - 4) + 5 + (1 - 324 + fn(-3223 / 1 * 914 - (111  2 /355 * (3 + 4))))");
-    let ast = parse(expr.clone());
-
-    match ast {
-        Ok(ast) => {
-            dbg!(ast);
-        },
+use parse::parse_with_radix;
+use std::process::ExitCode;
+use warnings::*;
+
+/// Returns warnings about flags raised by evaluation.
+///
+/// Overflow and underflow imply rounding, so inexact result is not reported with them.
+fn warnings_of(status: Status) -> Vec<&'static str> {
+    let flags = [
+        (status.invalid(), WARN__INVALID),
+        (status.div_by_zero(), WARN__DIV_BY_ZERO),
+        (status.overflow(), WARN__OVERFLOW),
+        (status.underflow(), WARN__UNDERFLOW),
+        (
+            status.inexact() && !status.overflow() && !status.underflow(),
+            WARN__INEXACT,
+        ),
+    ];
+
+    flags
+        .into_iter()
+        .filter_map(|(is_raised, msg)| is_raised.then_some(msg))
+        .collect()
+}
+
+const USAGE: &str = "\
+Usage: term_math [OPTIONS] EXPRESSION
+
+Options:
+  --radix N               Radix of literals and results, 2..=36, 10 by default
+  --precision N           Significant digits of results, 64 by default
+  --degrees, --radians    Angle unit of trigonometric functions
+  --half-even, --half-up, --half-down, --toward-zero, --floor, --ceiling
+                          Rounding mode, half-even by default
+  --exact                 Evaluate exactly in fractions
+  --approximate           Evaluate exactly and round the result once,
+                          approximately if it has no exact value
+  --help                  Print this message
+
+Literals start with a decimal digit in any radix, so in radixes above 10
+a literal starting with a letter needs a leading zero or a radix prefix,
+e.g. `0ff + 1` or `16#ff + 1` with `--radix 16`. In radixes above 14,
+where `e` is a digit, exponent follows `@`, e.g. `1@-8`.
+";
+
+fn main() -> ExitCode {
+    let mut config = Config::new();
+    let mut is_exact = false;
+    let mut is_approximate = false;
+    let mut radix = NumberSpec::DEFAULT.radix();
+    let mut prec = NumberSpec::DEFAULT.prec();
+    let mut expr_parts = Vec::new();
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        let (name, value) = match arg.split_once('=') {
+            Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+            _ => (arg.as_str(), None),
+        };
+
+        match name {
+            "--radix" | "--precision" => {
+                let Some(value) = value.or_else(|| args.next()) else {
+                    eprintln!("missing value of {}", name);
+                    return ExitCode::FAILURE;
+                };
+
+                let parsed = match name {
+                    "--radix" => value.parse().map(|value| radix = value).is_ok(),
+                    _ => value.parse().map(|value| prec = value).is_ok(),
+                };
+
+                if !parsed {
+                    eprintln!("invalid value of {}: {}", name, value);
+                    return ExitCode::FAILURE;
+                }
+            }
+            "--degrees" | "--deg" => config = config.with_angle_unit(AngleUnit::Degrees),
+            "--radians" | "--rad" => config = config.with_angle_unit(AngleUnit::Radians),
+            "--half-even" => config = config.with_rounding_mode(RoundingMode::HalfEven),
+            "--half-up" => config = config.with_rounding_mode(RoundingMode::HalfUp),
+            "--half-down" => config = config.with_rounding_mode(RoundingMode::HalfDown),
+            "--toward-zero" => config = config.with_rounding_mode(RoundingMode::TowardZero),
+            "--floor" => config = config.with_rounding_mode(RoundingMode::Floor),
+            "--ceiling" => config = config.with_rounding_mode(RoundingMode::Ceiling),
+            "--help" | "-h" => {
+                print!("{}", USAGE);
+                return ExitCode::SUCCESS;
+            }
+            "--exact" => is_exact = true,
+            "--approximate" | "--approx" => is_approximate = true,
+            _ => expr_parts.push(arg),
+        }
+    }
+
+    let Some(number_spec) = NumberSpec::new(radix, prec) else {
+        eprintln!("radix must be in 2..=36 and precision must be positive");
+        return ExitCode::FAILURE;
+    };
+    config = config.with_number_spec(number_spec);
+
+    let expr = expr_parts.join(" ");
+    let mut status = Status::default();
+    let res = parse_with_radix(&expr, radix).and_then(|ast| match (is_exact, is_approximate) {
+        (_, true) => {
+            let (res, res_status) = evaluate_approximately_with(&ast, &config);
+            status = res_status;
+            res.map(|num| num.to_string())
+        }
+        (true, false) => evaluate_with::<Rational>(&ast, &config).map(|num| num.to_string()),
+        (false, false) => {
+            let (res, res_status) = evaluate_with_status::<DynNumber>(&ast, &config);
+            status = res_status;
+            res.map(|num| num.to_string())
+        }
+    });
+
+    match res {
+        Ok(num) => {
+            for msg in warnings_of(status) {
+                let warning = Notification::new_warning(&expr, msg.to_owned(), 0, expr.len());
+                eprint!("{}", warning);
+            }
+
+            println!("{}", num);
+            ExitCode::SUCCESS
+        }
         Err(wrapper) => {
             let (msg, span) = wrapper.to_tuple();
             let err = Notification::new_error(&expr, msg, span.start(), span.end());
-            print!("{}", err);
-        } 
+            eprint!("{}", err);
+            ExitCode::FAILURE
+        }
     }
-    */
 }
@@ -0,0 +1,5 @@
+pub const WARN__INEXACT: &str = "Result is inexact, it is rounded to precision.";
+pub const WARN__OVERFLOW: &str = "Result overflowed, it is out of exponent range.";
+pub const WARN__UNDERFLOW: &str = "Result underflowed, it lost digits below exponent range.";
+pub const WARN__DIV_BY_ZERO: &str = "Division by zero, result is infinite.";
+pub const WARN__INVALID: &str = "Invalid operation, result is NaN.";